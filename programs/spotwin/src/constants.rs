@@ -1 +1,11 @@
-pub const LOCK_PERIOD_SLOTS: u64 = 6_480_000;
+pub const CLAIM_PERIOD_SLOTS: u64 = 6_480_000;
+pub const LEGACY_LOCK_PERIOD_SLOTS: u64 = 6_480_000; // stake lock before ProtocolConfig; see migrate_stake
+pub const BPS_DENOMINATOR: u16 = 10_000;
+pub const MAX_ALLOWED_MINTS: usize = 8;
+pub const LEADERBOARD_SIZE: usize = 10;
+pub const MAX_TIERS: usize = LEADERBOARD_SIZE;
+pub const MAX_QUESTIONS: usize = 32; // width of the u32 attempt mask
+pub const MAX_OPTIONS: u8 = 16;
+pub const MAX_RESOLVERS: usize = 8;
+pub const MAX_TITLE_LEN: usize = 64;       // bytes
+pub const MAX_METADATA_URI_LEN: usize = 200; // bytes
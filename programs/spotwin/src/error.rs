@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 pub enum ErrorCode {
     #[msg("Contest is closed or not accepting entries")] ContestClosed,
     #[msg("Arithmetic overflow occurred")] NumericalOverflow,
-    #[msg("Must attempt exactly 9 questions")] InvalidAttemptMask,
+    #[msg("Attempt mask outside the contest's questions or attempt range")] InvalidAttemptMask,
     #[msg("Answer bits outside attempted mask")] InvalidAnswerBits,
     #[msg("Invalid owner")] InvalidOwner,
     #[msg("Invalid mint")] InvalidMint,
@@ -29,5 +29,99 @@ pub enum ErrorCode {
     StakeLocked,
     #[msg("Invalid unstake amount")]
     InvalidUnstakeAmount,
+    #[msg("Payout root has not been posted")]
+    PayoutRootNotSet,
+    #[msg("Merkle proof does not match the payout root")]
+    InvalidProof,
+    #[msg("Prize has already been paid to this player")]
+    AlreadyPaid,
+    #[msg("Payout would exceed the total committed in the payout root")]
+    PayoutExceedsCommitted,
+    #[msg("Payouts are still outstanding and the claim window is open")]
+    SettlementNotReady,
+    #[msg("Contest can no longer be cancelled")]
+    ContestNotCancellable,
+    #[msg("Contest has not been cancelled")]
+    ContestNotCancelled,
+    #[msg("Entry fee has already been refunded")]
+    AlreadyRefunded,
+    #[msg("Lock slot has not been reached yet")]
+    LockSlotNotReached,
+    #[msg("Contest has not been settled")]
+    ContestNotSettled,
+    #[msg("Nothing left in the vault to sweep")]
+    NothingToSweep,
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    #[msg("Basis points must not exceed 10000")]
+    InvalidFeeBps,
+    #[msg("Too many allowed mints")]
+    TooManyMints,
+    #[msg("Mint is not allowed as a pool mint")]
+    MintNotAllowed,
+    #[msg("Rake exceeds the protocol maximum")]
+    RakeTooHigh,
+    #[msg("Payout total exceeds the contest prize pool")]
+    PayoutExceedsPrizePool,
+    #[msg("Contest has reached its maximum number of entries")]
+    ContestFull,
+    #[msg("min_entries must not exceed max_entries")]
+    InvalidEntryLimits,
+    #[msg("Participant has already been scored")]
+    AlreadyScored,
+    #[msg("Tier table must only be set for Tiered contests, with non-zero tiers summing to exactly 10000 bps")]
+    InvalidTierTable,
+    #[msg("Instruction does not apply to this contest's payout scheme")]
+    WrongPayoutScheme,
+    #[msg("Not every participant has been scored yet")]
+    ScoringIncomplete,
+    #[msg("Participant is not owed a prize")]
+    NoPrize,
+    #[msg("Payouts have not been committed yet")]
+    PayoutsNotCommitted,
+    #[msg("Invalid question count or attempt range")]
+    InvalidQuestionConfig,
+    #[msg("Question weights must be non-zero, one per question")]
+    InvalidQuestionWeights,
+    #[msg("Tiebreaker value must be given exactly when the contest has a tiebreaker")]
+    InvalidTiebreaker,
+    #[msg("Void mask covers questions outside the contest")]
+    InvalidVoidMask,
+    #[msg("Answer key dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Answer key is still open to disputes")]
+    AnswerKeyNotFinal,
+    #[msg("Dispute has already been resolved")]
+    DisputeAlreadyResolved,
+    #[msg("Instruction does not apply to this contest's resolution mode")]
+    WrongResolutionMode,
+    #[msg("Feed bindings must cover every question of a binary contest")]
+    InvalidFeedBindings,
+    #[msg("Feed account does not match its binding or is malformed")]
+    InvalidFeed,
+    #[msg("Feed has not published since the contest locked")]
+    FeedStale,
+    #[msg("Resolver set must be non-empty, unique and have a threshold between 1 and its size")]
+    InvalidResolverSet,
+    #[msg("Signer is not one of the contest's resolvers")]
+    NotAResolver,
+    #[msg("Lock deadline must be set for exactly the contest's lock mode")]
+    InvalidLockDeadline,
+    #[msg("Contest title or metadata URI is too long")]
+    MetadataTooLong,
+    #[msg("Contest metadata is frozen once a player has joined")]
+    MetadataFrozen,
+    #[msg("Answer key and salt do not match the committed hash")]
+    AnswerCommitmentMismatch,
+    #[msg("Payout root has already been posted")]
+    PayoutRootAlreadySet,
+    #[msg("Payout total must equal the prize pool")]
+    PayoutTotalMismatch,
+    #[msg("Contest's answer source is not set up yet")]
+    ResolutionNotConfigured,
+    #[msg("Stake account already uses the current layout")]
+    StakeAlreadyMigrated,
+    #[msg("A committed answer key may only void questions challenged by an upheld dispute")]
+    QuestionNotVoidable,
 
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestStatus, ResolutionMode};
+use crate::state::feed::{FeedBindings, QuestionFeed};
+
+/// Binds each question of a `ResolutionMode::Feed` contest to a feed account
+/// and threshold. Part of contest setup: joining stays closed until it runs,
+/// so players always see the bindings they are predicting against.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct BindQuestionFeeds<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = creator,
+        constraint = contest.status == ContestStatus::Open @ErrorCode::ContestClosed,
+        constraint = contest.resolution_mode == ResolutionMode::Feed @ErrorCode::WrongResolutionMode,
+        constraint = contest.total_entries == 0 @ErrorCode::InvalidFeedBindings,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = FeedBindings::LEN,
+        seeds = [b"feeds", &contest_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub feed_bindings: Box<Account<'info, FeedBindings>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<BindQuestionFeeds>,
+    contest_id: u64,
+    questions: Vec<QuestionFeed>,
+    tiebreaker_feed: Option<Pubkey>,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    // Feeds resolve over/under, so only binary questions can be bound.
+    require!(
+        contest.option_count == 2 && questions.len() == contest.question_count as usize,
+        ErrorCode::InvalidFeedBindings
+    );
+    require!(
+        tiebreaker_feed.is_some() == contest.has_tiebreaker,
+        ErrorCode::InvalidTiebreaker
+    );
+
+    let feed_bindings = &mut ctx.accounts.feed_bindings;
+    feed_bindings.contest_id = contest_id;
+    feed_bindings.questions = questions;
+    feed_bindings.tiebreaker_feed = tiebreaker_feed.unwrap_or_default();
+    feed_bindings.bump = ctx.bumps.feed_bindings;
+    contest.resolution_ready = true;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+use crate::state::config::ProtocolConfig;
+use crate::state::contest::{Contest, ContestStatus};
+
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct CancelContest<'info> {
+
+    /// The contest creator or the protocol admin.
+    #[account(
+        constraint = authority.key() == contest.creator || authority.key() == config.admin
+            @ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = matches!(
+            contest.status,
+            ContestStatus::Open | ContestStatus::Locked | ContestStatus::AnswerKeyPosted
+        ) @ErrorCode::ContestNotCancellable,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+}
+
+#[event]
+pub struct ContestCancelled {
+    pub contest_id: u64,
+    pub slot:       u64,
+}
+
+pub fn handler(ctx: Context<CancelContest>, contest_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let contest = &mut ctx.accounts.contest;
+
+    // Once winners have been paid the vault no longer covers every entry fee.
+    require!(contest.paid_so_far == 0, ErrorCode::ContestNotCancellable);
+
+    contest.status = ContestStatus::Cancelled;
+
+    emit!(ContestCancelled {
+        contest_id,
+        slot: clock.slot,
+    });
+    Ok(())
+}
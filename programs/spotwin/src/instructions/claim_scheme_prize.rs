@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::error::ErrorCode;
+use crate::instructions::claim_prize::PrizeClaimed;
+use crate::state::contest::{Contest, ContestStatus, PayoutScheme};
+use crate::state::leaderboard::Leaderboard;
+use crate::state::participant::Participant;
+
+/// Pays a winner of a computed payout scheme. The amount comes from the
+/// scoring results and the prize pool, never from the caller.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct ClaimSchemePrize<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = pool_mint,
+        constraint = contest.status == ContestStatus::AnswerKeyPosted @ErrorCode::ContestNotAnswerKeyPosted,
+        constraint = contest.payout_scheme != PayoutScheme::MerkleRoot @ErrorCode::WrongPayoutScheme,
+        constraint = contest.payouts_committed() @ErrorCode::ScoringIncomplete,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        mut,
+        seeds = [b"participant".as_ref(), &contest_id.to_le_bytes(), player.key().as_ref()],
+        bump,
+        has_one = player @ErrorCode::InvalidParticipant,
+    )]
+    pub participant: Box<Account<'info, Participant>>,
+
+    #[account(
+        seeds = [b"leaderboard", &contest_id.to_le_bytes()[..]],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            contest_id.to_le_bytes().as_ref(),
+            pool_mint.key().as_ref()
+        ],
+        bump = contest.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA authority
+    #[account(
+        seeds = [b"vault_authority", &contest_id.to_le_bytes()],
+        bump = contest.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = player_token.owner == player.key() @ErrorCode::InvalidOwner,
+        constraint = player_token.mint == pool_mint.key() @ErrorCode::InvalidMint,
+    )]
+    pub player_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub pool_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<ClaimSchemePrize>, contest_id: u64) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let participant = &mut ctx.accounts.participant;
+
+    require!(contest.answer_key_final(Clock::get()?.slot), ErrorCode::AnswerKeyNotFinal);
+    require!(participant.paid_amount == 0, ErrorCode::AlreadyPaid);
+
+    let amount = contest.scheme_entitlement(participant, &ctx.accounts.leaderboard);
+    require!(amount > 0, ErrorCode::NoPrize);
+
+    let new_paid_so_far = contest.paid_so_far.checked_add(amount).ok_or(ErrorCode::NumericalOverflow)?;
+    require!(new_paid_so_far <= contest.payout_total, ErrorCode::PayoutExceedsCommitted);
+
+    let contest_id_bytes = contest.contest_id.to_le_bytes();
+    let vault_authority_bump_bytes = &[contest.vault_authority_bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        &contest_id_bytes,
+        vault_authority_bump_bytes,
+    ]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.pool_mint.to_account_info(),
+                to: ctx.accounts.player_token.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.pool_mint.decimals,
+    )?;
+
+    participant.paid_amount = amount;
+    contest.paid_so_far = new_paid_so_far;
+
+    emit!(PrizeClaimed {
+        contest_id,
+        player: participant.player,
+        amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RESOLVERS;
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestStatus, ResolutionMode};
+use crate::state::resolution_votes::ResolutionVotes;
+
+/// Names the M-of-N resolver set of a `ResolutionMode::Quorum` contest. Part
+/// of contest setup: joining stays closed until it runs.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct ConfigureResolvers<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = creator,
+        constraint = contest.status == ContestStatus::Open @ErrorCode::ContestClosed,
+        constraint = contest.resolution_mode == ResolutionMode::Quorum @ErrorCode::WrongResolutionMode,
+        constraint = contest.total_entries == 0 @ErrorCode::InvalidResolverSet,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ResolutionVotes::LEN,
+        seeds = [b"votes", &contest_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub resolution_votes: Box<Account<'info, ResolutionVotes>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureResolvers>,
+    contest_id: u64,
+    resolvers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        !resolvers.is_empty() && resolvers.len() <= MAX_RESOLVERS,
+        ErrorCode::InvalidResolverSet
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= resolvers.len(),
+        ErrorCode::InvalidResolverSet
+    );
+    for (i, resolver) in resolvers.iter().enumerate() {
+        require!(!resolvers[..i].contains(resolver), ErrorCode::InvalidResolverSet);
+    }
+
+    let votes = &mut ctx.accounts.resolution_votes;
+    votes.contest_id = contest_id;
+    votes.resolvers = resolvers;
+    votes.threshold = threshold;
+    votes.submissions = Vec::new();
+    votes.disagreements = 0;
+    votes.bump = ctx.bumps.resolution_votes;
+    ctx.accounts.contest.resolution_ready = true;
+
+    Ok(())
+}
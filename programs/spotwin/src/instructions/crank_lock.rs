@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+use crate::instructions::cancel_contest::ContestCancelled;
+use crate::instructions::lock_contest::ContestLocked;
+use crate::state::contest::{Contest, ContestStatus};
+
+/// Permissionless: once the lock deadline has passed anyone may lock the contest,
+/// so the deadline doesn't depend on the creator's backend. Locking below
+/// `min_entries` cancels the contest instead.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct CrankLock<'info> {
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = contest.status == ContestStatus::Open @ErrorCode::ContestClosed,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+}
+
+pub fn handler(ctx: Context<CrankLock>, contest_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.slot;
+    let contest = &mut ctx.accounts.contest;
+
+    require!(contest.is_past_lock(&clock), ErrorCode::LockSlotNotReached);
+    contest.lock(now)?;
+
+    if contest.status == ContestStatus::Cancelled {
+        emit!(ContestCancelled {
+            contest_id,
+            slot: now,
+        });
+    } else {
+        emit!(ContestLocked {
+            contest_id,
+            prize_pool: contest.prize_pool,
+            slot: now,
+        });
+    }
+    Ok(())
+}
@@ -1,7 +1,105 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::state::contest::{Contest, ContestStatus};
+use crate::error::ErrorCode;
+use crate::state::config::ProtocolConfig;
+use crate::constants::{BPS_DENOMINATOR, MAX_OPTIONS, MAX_QUESTIONS, MAX_TIERS};
+use crate::state::contest::{answer_width, Contest, ContestMetadata, ContestStatus, LockMode, PayoutScheme, ResolutionMode};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateContestParams {
+    pub entry_fee: u64,
+    pub lock_slot: u64,
+    pub rake_bps: u16,
+    pub max_entries: u32, // 0 = no cap
+    pub min_entries: u32,
+    pub payout_scheme: PayoutScheme,
+    pub tier_bps: Vec<u16>, // Tiered only: share per rank, best first
+    pub question_count: u8,
+    pub min_attempts: u8,   // players must attempt between min and max questions
+    pub max_attempts: u8,
+    pub option_count: u8,   // choices per question, 2 for yes/no
+    pub question_weights: Vec<u8>, // one per question, or empty for 1 point each
+    pub has_tiebreaker: bool,
+    pub dispute_period_slots: u64, // 0 = payouts may start right after the answer key
+    pub resolution_mode: ResolutionMode,
+    pub lock_mode: LockMode,
+    pub lock_unix_timestamp: i64, // UnixTimestamp mode only; lock_slot must then be 0
+    pub metadata: ContestMetadata,
+    pub answer_commitment: [u8; 32], // Manual mode only; zero = no commitment
+}
+
+impl CreateContestParams {
+    fn validate_questions(&self) -> Result<()> {
+        require!(
+            self.question_count > 0 && self.question_count as usize <= MAX_QUESTIONS,
+            ErrorCode::InvalidQuestionConfig
+        );
+        require!(
+            self.min_attempts > 0
+                && self.min_attempts <= self.max_attempts
+                && self.max_attempts <= self.question_count,
+            ErrorCode::InvalidQuestionConfig
+        );
+        require!(
+            (2..=MAX_OPTIONS).contains(&self.option_count),
+            ErrorCode::InvalidQuestionConfig
+        );
+        // Packed answers must fit in a u64.
+        require!(
+            self.question_count as u32 * answer_width(self.option_count) <= u64::BITS,
+            ErrorCode::InvalidQuestionConfig
+        );
+        Ok(())
+    }
+
+    fn validate_lock(&self) -> Result<()> {
+        let valid = match self.lock_mode {
+            LockMode::Slot => self.lock_unix_timestamp == 0,
+            LockMode::UnixTimestamp => self.lock_slot == 0 && self.lock_unix_timestamp > 0,
+        };
+        require!(valid, ErrorCode::InvalidLockDeadline);
+        Ok(())
+    }
+
+    fn weight_table(&self) -> Result<[u8; MAX_QUESTIONS]> {
+        let mut table = [0u8; MAX_QUESTIONS];
+        if self.question_weights.is_empty() {
+            table[..self.question_count as usize].fill(1);
+            return Ok(table);
+        }
+
+        require!(
+            self.question_weights.len() == self.question_count as usize
+                && self.question_weights.iter().all(|w| *w > 0),
+            ErrorCode::InvalidQuestionWeights
+        );
+        table[..self.question_weights.len()].copy_from_slice(&self.question_weights);
+        Ok(table)
+    }
+
+    fn tier_table(&self) -> Result<[u16; MAX_TIERS]> {
+        let mut table = [0u16; MAX_TIERS];
+        if self.payout_scheme != PayoutScheme::Tiered {
+            require!(self.tier_bps.is_empty(), ErrorCode::InvalidTierTable);
+            return Ok(table);
+        }
+
+        require!(
+            !self.tier_bps.is_empty() && self.tier_bps.len() <= MAX_TIERS,
+            ErrorCode::InvalidTierTable
+        );
+        let total: u32 = self.tier_bps.iter().map(|bps| *bps as u32).sum();
+        // Unplaced tiers are rescaled away, so the table only means what it
+        // says when it covers the whole pool.
+        require!(total == BPS_DENOMINATOR as u32, ErrorCode::InvalidTierTable);
+        // Every rank that places must get something, or its share is unassigned.
+        require!(self.tier_bps.iter().all(|bps| *bps > 0), ErrorCode::InvalidTierTable);
+
+        table[..self.tier_bps.len()].copy_from_slice(&self.tier_bps);
+        Ok(table)
+    }
+}
 
 #[derive(Accounts)]
 #[instruction(contest_id: u64)]
@@ -9,6 +107,13 @@ pub struct CreateContest<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_mint_allowed(&pool_mint.key()) @ErrorCode::MintNotAllowed,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(
         init,
         payer = creator,
@@ -45,20 +150,75 @@ pub struct CreateContest<'info> {
 pub fn handler(
     ctx: Context<CreateContest>,
     contest_id: u64,
-    entry_fee: u64,
-    lock_slot: u64,
+    params: CreateContestParams,
 ) -> Result<()> {
+    require!(params.rake_bps <= ctx.accounts.config.max_rake_bps, ErrorCode::RakeTooHigh);
+    require!(
+        params.max_entries == 0 || params.min_entries <= params.max_entries,
+        ErrorCode::InvalidEntryLimits
+    );
+    params.validate_questions()?;
+    params.validate_lock()?;
+    params.metadata.validate()?;
+    // Feed and quorum keys aren't revealed by the creator, so there's
+    // nothing to hold them to.
+    require!(
+        params.answer_commitment == [0u8; 32] || params.resolution_mode == ResolutionMode::Manual,
+        ErrorCode::WrongResolutionMode
+    );
+    require!(
+        params.resolution_mode != ResolutionMode::Feed
+            || ctx.accounts.config.feed_program != Pubkey::default(),
+        ErrorCode::InvalidFeed
+    );
+    let tier_bps = params.tier_table()?;
+    let question_weights = params.weight_table()?;
+
     let contest = &mut ctx.accounts.contest;
     contest.creator = ctx.accounts.creator.key();
     contest.contest_id = contest_id;
     contest.pool_mint = ctx.accounts.pool_mint.key();
-    contest.entry_fee = entry_fee;
-    contest.lock_slot = lock_slot;
+    contest.entry_fee = params.entry_fee;
+    contest.lock_slot = params.lock_slot;
     contest.status = ContestStatus::Open;
     contest.answer_key = 0;
     contest.payout_root = [0u8; 32];
     contest.winner_count = 0;
+    contest.payout_total = 0;
     contest.paid_so_far = 0;
+    contest.claim_deadline_slot = 0;
+    contest.protocol_fee_bps = ctx.accounts.config.protocol_fee_bps;
+    contest.rake_bps = params.rake_bps;
+    contest.prize_pool = 0;
+    contest.max_entries = params.max_entries;
+    contest.min_entries = params.min_entries;
+    contest.scored_count = 0;
+    contest.payout_scheme = params.payout_scheme;
+    contest.tier_bps = tier_bps;
+    contest.perfect_count = 0;
+    contest.total_score = 0;
+    contest.sponsored_amount = 0;
+    contest.question_count = params.question_count;
+    contest.min_attempts = params.min_attempts;
+    contest.max_attempts = params.max_attempts;
+    contest.option_count = params.option_count;
+    contest.question_weights = question_weights;
+    contest.has_tiebreaker = params.has_tiebreaker;
+    contest.tiebreaker_result = 0;
+    contest.void_mask = 0;
+    contest.dispute_period_slots = params.dispute_period_slots;
+    contest.answer_key_slot = 0;
+    contest.answer_key_round = 0;
+    contest.open_disputes = 0;
+    contest.resolution_mode = params.resolution_mode;
+    contest.lock_mode = params.lock_mode;
+    contest.lock_unix_timestamp = params.lock_unix_timestamp;
+    contest.metadata = params.metadata;
+    contest.answer_commitment = params.answer_commitment;
+    contest.fees_swept = false;
+    contest.feed_program = ctx.accounts.config.feed_program;
+    contest.resolution_ready = params.resolution_mode == ResolutionMode::Manual;
+    contest.upheld_questions = 0;
     contest.contest_bump = ctx.bumps.contest;
     contest.vault_bump = ctx.bumps.vault; 
     contest.vault_authority_bump = ctx.bumps.vault_authority;
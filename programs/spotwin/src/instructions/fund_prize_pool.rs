@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestStatus};
+use crate::state::sponsorship::Sponsorship;
+
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct FundPrizePool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = pool_mint,
+        constraint = contest.status == ContestStatus::Open @ErrorCode::ContestClosed,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = Sponsorship::LEN,
+        seeds = [b"sponsorship", &contest_id.to_le_bytes()[..], sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Box<Account<'info, Sponsorship>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            contest_id.to_le_bytes().as_ref(),
+            pool_mint.key().as_ref()
+        ],
+        bump = contest.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = sponsor_token.owner == sponsor.key() @ErrorCode::InvalidOwner,
+        constraint = sponsor_token.mint == pool_mint.key() @ErrorCode::InvalidMint,
+    )]
+    pub sponsor_token: Account<'info, TokenAccount>,
+
+    pub pool_mint: Account<'info, Mint>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct PrizePoolFunded {
+    pub contest_id: u64,
+    pub sponsor:    Pubkey,
+    pub amount:     u64,
+}
+
+pub fn handler(ctx: Context<FundPrizePool>, contest_id: u64, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidArguments);
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::Transfer {
+            from: ctx.accounts.sponsor_token.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.sponsor.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    let sponsorship = &mut ctx.accounts.sponsorship;
+    sponsorship.contest_id = contest_id;
+    sponsorship.sponsor = ctx.accounts.sponsor.key();
+    sponsorship.bump = ctx.bumps.sponsorship;
+    sponsorship.amount = sponsorship.amount.checked_add(amount).ok_or(ErrorCode::NumericalOverflow)?;
+
+    let contest = &mut ctx.accounts.contest;
+    contest.sponsored_amount = contest
+        .sponsored_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    emit!(PrizePoolFunded {
+        contest_id,
+        sponsor: sponsorship.sponsor,
+        amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, MAX_ALLOWED_MINTS};
+use crate::error::ErrorCode;
+use crate::program::Spotwin;
+use crate::state::config::ProtocolConfig;
+use crate::state::contest::LockMode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub treasury: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub max_rake_bps: u16,
+    pub allowed_mints: Vec<Pubkey>,
+    pub stake_mint: Pubkey,
+    pub stake_lock_mode: LockMode,
+    pub stake_lock_period: u64,
+    pub dispute_bond_lamports: u64,
+    pub feed_program: Pubkey,
+}
+
+impl ConfigParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.protocol_fee_bps <= BPS_DENOMINATOR, ErrorCode::InvalidFeeBps);
+        require!(self.max_rake_bps <= BPS_DENOMINATOR, ErrorCode::InvalidFeeBps);
+        require!(self.allowed_mints.len() <= MAX_ALLOWED_MINTS, ErrorCode::TooManyMints);
+        Ok(())
+    }
+
+    pub fn apply(self, config: &mut ProtocolConfig) {
+        config.treasury = self.treasury;
+        config.protocol_fee_bps = self.protocol_fee_bps;
+        config.max_rake_bps = self.max_rake_bps;
+        config.allowed_mints = self.allowed_mints;
+        config.stake_mint = self.stake_mint;
+        config.stake_lock_mode = self.stake_lock_mode;
+        config.stake_lock_period = self.stake_lock_period;
+        config.dispute_bond_lamports = self.dispute_bond_lamports;
+        config.feed_program = self.feed_program;
+    }
+}
+
+/// Only the program's upgrade authority can create the config, so the
+/// singleton can't be front-run after deployment.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Spotwin>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+    params.validate()?;
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.bump = ctx.bumps.config;
+    params.apply(config);
+
+    Ok(())
+}
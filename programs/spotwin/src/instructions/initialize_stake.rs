@@ -1,13 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
+use crate::error::ErrorCode;
+use crate::state::config::ProtocolConfig;
+
 #[derive(Accounts)]
 #[instruction()]
 pub struct InitializeStake<'info> {
-    /// Whoever pays the rent (you, or a deployer script)
+    /// Protocol admin; pays the rent
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ErrorCode::Unauthorized,
+        constraint = config.stake_mint == pool_mint.key() @ErrorCode::InvalidMint,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
     /// The SPL mint users will stake (USDC / SPOT)
     pub pool_mint: Account<'info, Mint>,
 
@@ -21,6 +21,7 @@ pub struct JoinContest<'info> {
         bump = contest.contest_bump,
         has_one = pool_mint,
         constraint = contest.status == ContestStatus::Open @ErrorCode::ContestClosed,
+        constraint = contest.resolution_ready @ErrorCode::ResolutionNotConfigured,
     )]
     pub contest: Box<Account<'info, Contest>>,
  
@@ -78,6 +79,9 @@ pub fn handler(
     let clock = Clock::get()?;
     let contest = &mut ctx.accounts.contest;
 
+    require!(!contest.is_past_lock(&clock), ErrorCode::ContestClosed);
+    require!(!contest.is_full(), ErrorCode::ContestFull);
+
     if contest.entry_fee > 0 {
         let cpi_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -93,6 +97,12 @@ pub fn handler(
     p.player = ctx.accounts.player.key();
     p.attempt_mask = 0;
     p.answer_bits = 0;
+    p.paid_amount = 0;
+    p.refunded = false;
+    p.score = 0;
+    p.scored = false;
+    p.joined_slot = clock.slot;
+    p.tiebreaker_prediction = 0;
 
     contest.total_entries = contest
         .total_entries
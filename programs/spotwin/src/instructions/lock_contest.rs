@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
 
+use crate::instructions::cancel_contest::ContestCancelled;
 use crate::state::contest::{Contest, ContestStatus};
 
 #[derive(Accounts)]
@@ -19,8 +20,29 @@ pub struct LockContest<'info> {
     pub contest: Box<Account<'info, Contest>>,
 }
 
-pub fn handler(ctx: Context<LockContest>, _contest_id: u64) -> Result<()> {
+#[event]
+pub struct ContestLocked {
+    pub contest_id: u64,
+    pub prize_pool: u64,
+    pub slot:       u64,
+}
+
+pub fn handler(ctx: Context<LockContest>, contest_id: u64) -> Result<()> {
+    let now = Clock::get()?.slot;
     let contest = &mut ctx.accounts.contest;
-    contest.status = ContestStatus::Locked;
+    contest.lock(now)?;
+
+    if contest.status == ContestStatus::Cancelled {
+        emit!(ContestCancelled {
+            contest_id,
+            slot: now,
+        });
+    } else {
+        emit!(ContestLocked {
+            contest_id,
+            prize_pool: contest.prize_pool,
+            slot: now,
+        });
+    }
     Ok(())
 }
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::LEGACY_LOCK_PERIOD_SLOTS;
+use crate::error::ErrorCode;
+use crate::state::contest::LockMode;
+use crate::state::stake::StakeAccount;
+
+/// Permissionless: grows a stake account created at `StakeAccount::LEGACY_LEN`
+/// to the current layout so `stake_tokens` / `unstake_tokens` can load it.
+/// Legacy stakes were locked for `LEGACY_LOCK_PERIOD_SLOTS` from their start
+/// slot, which is what they are snapshotted with.
+#[derive(Accounts)]
+pub struct MigrateStake<'info> {
+    /// Pays the extra rent.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    /// CHECK: pure identity; used only as seed for `stake_acct`.
+    pub staker: UncheckedAccount<'info>,
+
+    /// CHECK: legacy layout that `Account<StakeAccount>` cannot deserialize;
+    /// pinned by seeds and program ownership, size checked in the handler.
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub stake_acct: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateStake>) -> Result<()> {
+    let info = ctx.accounts.stake_acct.to_account_info();
+    require!(info.data_len() == StakeAccount::LEGACY_LEN, ErrorCode::StakeAlreadyMigrated);
+
+    let migrated = {
+        let data = info.try_borrow_data()?;
+        StakeAccount {
+            owner: Pubkey::try_from(&data[8..40]).unwrap(),
+            amount: u64::from_le_bytes(data[40..48].try_into().unwrap()),
+            start_slot: u64::from_le_bytes(data[48..56].try_into().unwrap()),
+            start_unix_timestamp: 0,
+            lock_mode: LockMode::Slot,
+            lock_period: LEGACY_LOCK_PERIOD_SLOTS,
+        }
+    };
+
+    let shortfall = Rent::get()?
+        .minimum_balance(StakeAccount::LEN)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.fee_payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    info.realloc(StakeAccount::LEN, false)?;
+    migrated.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
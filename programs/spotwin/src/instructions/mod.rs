@@ -1,3 +1,5 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod create_contest;
 pub mod join_contest;
 pub mod update_answers;
@@ -7,7 +9,27 @@ pub mod post_payout_root;
 pub mod send_batch;
 pub mod stake_tokens;
 pub mod unstake_tokens;
+pub mod migrate_stake;
 pub mod initialize_stake;
+pub mod claim_prize;
+pub mod settle_contest;
+pub mod cancel_contest;
+pub mod refund_entry;
+pub mod crank_lock;
+pub mod sweep_fees;
+pub mod initialize_config;
+pub mod update_config;
+pub mod score_participant;
+pub mod claim_scheme_prize;
+pub mod fund_prize_pool;
+pub mod refund_sponsor;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod bind_question_feeds;
+pub mod resolve_from_feed;
+pub mod configure_resolvers;
+pub mod submit_resolution;
+pub mod update_metadata;
 
 pub use create_contest::*;
 pub use join_contest::*;
@@ -18,4 +40,24 @@ pub use post_payout_root::*;
 pub use send_batch::*;
 pub use stake_tokens::*;
 pub use unstake_tokens::*;
-pub use initialize_stake::*;
\ No newline at end of file
+pub use migrate_stake::*;
+pub use initialize_stake::*;
+pub use claim_prize::*;
+pub use settle_contest::*;
+pub use cancel_contest::*;
+pub use refund_entry::*;
+pub use crank_lock::*;
+pub use sweep_fees::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use score_participant::*;
+pub use claim_scheme_prize::*;
+pub use fund_prize_pool::*;
+pub use refund_sponsor::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use bind_question_feeds::*;
+pub use resolve_from_feed::*;
+pub use configure_resolvers::*;
+pub use submit_resolution::*;
+pub use update_metadata::*;
\ No newline at end of file
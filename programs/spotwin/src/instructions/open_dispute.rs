@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::error::ErrorCode;
+use crate::state::config::ProtocolConfig;
+use crate::state::contest::{Contest, ContestStatus};
+use crate::state::dispute::Dispute;
+use crate::state::participant::Participant;
+
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = contest.status == ContestStatus::AnswerKeyPosted @ErrorCode::ContestNotAnswerKeyPosted,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        seeds = [b"participant".as_ref(), &contest_id.to_le_bytes(), player.key().as_ref()],
+        bump,
+        has_one = player @ErrorCode::InvalidParticipant,
+    )]
+    pub participant: Box<Account<'info, Participant>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        init,
+        payer = player,
+        space = Dispute::LEN,
+        seeds = [
+            b"dispute",
+            &contest_id.to_le_bytes()[..],
+            player.key().as_ref(),
+            &contest.answer_key_round.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub contest_id:         u64,
+    pub disputer:           Pubkey,
+    pub disputed_questions: u32,
+    pub bond_lamports:      u64,
+}
+
+pub fn handler(ctx: Context<OpenDispute>, contest_id: u64, disputed_questions: u32) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let contest = &mut ctx.accounts.contest;
+
+    require!(contest.in_dispute_window(now), ErrorCode::DisputeWindowClosed);
+    require!(
+        disputed_questions != 0 && disputed_questions & !contest.question_mask() == 0,
+        ErrorCode::InvalidArguments
+    );
+
+    let bond_lamports = ctx.accounts.config.dispute_bond_lamports;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.dispute.to_account_info(),
+            },
+        ),
+        bond_lamports,
+    )?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.contest_id = contest_id;
+    dispute.disputer = ctx.accounts.player.key();
+    dispute.answer_key_round = contest.answer_key_round;
+    dispute.disputed_questions = disputed_questions;
+    dispute.bond_lamports = bond_lamports;
+    dispute.resolved = false;
+    dispute.upheld = false;
+    dispute.bump = ctx.bumps.dispute;
+
+    contest.open_disputes = contest
+        .open_disputes
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    emit!(DisputeOpened {
+        contest_id,
+        disputer: dispute.disputer,
+        disputed_questions,
+        bond_lamports,
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
 
-use crate::state::contest::{Contest, ContestStatus};
+use crate::state::contest::{Contest, ContestStatus, ResolutionMode};
 
 #[derive(Accounts)]
 #[instruction(contest_id: u64)]
@@ -14,24 +14,54 @@ pub struct PostAnswerKey<'info> {
         seeds = [b"contest", &contest_id.to_le_bytes()[..]],
         bump = contest.contest_bump,
         constraint = contest.status == ContestStatus::Locked @ErrorCode::ContestNotLocked,
+        constraint = contest.resolution_mode == ResolutionMode::Manual @ErrorCode::WrongResolutionMode,
         has_one = creator
     )]
     pub contest: Box<Account<'info, Contest>>,
 }
 
+#[event]
+pub struct AnswerKeyPosted {
+    pub contest_id:        u64,
+    pub answer_key:        u64,
+    pub void_mask:         u32,
+    pub tiebreaker_result: i64,
+}
+
 pub fn handler(
     ctx: Context<PostAnswerKey>,
-    _contest_id: u64,
-    answer_key: u16,
+    contest_id: u64,
+    answer_key: u64,
+    void_mask: u32,
+    tiebreaker_result: Option<i64>,
+    salt: [u8; 32],
 ) -> Result<()> {
-    
+    let now = Clock::get()?.slot;
     let contest = &mut ctx.accounts.contest;
-    require!(
-        answer_key < (1 << 12),
-        ErrorCode::InvalidAnswerKey
-    );
+    // A committed outcome can only be revealed, not chosen. The creator's one
+    // remedy is voiding questions, and only those an upheld dispute
+    // challenged; the reveal still carries their original answers, which
+    // are cleared before the key is posted.
+    let answer_key = if contest.has_answer_commitment() {
+        require!(
+            contest.opens_commitment(answer_key, tiebreaker_result.unwrap_or_default(), &salt),
+            ErrorCode::AnswerCommitmentMismatch
+        );
+        require!(
+            void_mask & !contest.upheld_questions == 0,
+            ErrorCode::QuestionNotVoidable
+        );
+        contest.without_voided(answer_key, void_mask)
+    } else {
+        answer_key
+    };
+    contest.post_answer_key(answer_key, void_mask, tiebreaker_result, now)?;
 
-    contest.answer_key = answer_key;
-    contest.status = ContestStatus::AnswerKeyPosted;
+    emit!(AnswerKeyPosted {
+        contest_id,
+        answer_key,
+        void_mask,
+        tiebreaker_result: contest.tiebreaker_result,
+    });
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
+use crate::constants::CLAIM_PERIOD_SLOTS;
 use crate::error::ErrorCode;
 
-use crate::state::contest::{Contest, ContestStatus};
+use crate::state::contest::{Contest, ContestStatus, PayoutScheme};
 
 #[derive(Accounts)]
 #[instruction(contest_id: u64)]
@@ -12,10 +13,10 @@ pub struct PostPayoutRoot<'info> {
         mut,
         seeds = [b"contest", &contest_id.to_le_bytes()[..]],
         bump = contest.contest_bump,
-        constraint = contest.status == ContestStatus::Locked
-            @ ErrorCode::ContestNotLocked,
-        constraint = contest.answer_key != 0
-            @ ErrorCode::AnswerKeyNotSet,
+        constraint = contest.status == ContestStatus::AnswerKeyPosted
+            @ ErrorCode::ContestNotAnswerKeyPosted,
+        constraint = contest.payout_scheme == PayoutScheme::MerkleRoot
+            @ ErrorCode::WrongPayoutScheme,
         has_one = creator
     )]
     pub contest: Box<Account<'info, Contest>>,
@@ -26,13 +27,25 @@ pub fn handler(
     _contest_id: u64,
     root: [u8; 32],
     winner_count: u32,
+    payout_total: u64,
 ) -> Result<()> {
+    let now = Clock::get()?.slot;
     let c = &mut ctx.accounts.contest;
 
+    require!(c.answer_key_final(now), ErrorCode::AnswerKeyNotFinal);
+
     require!(winner_count > 0 && winner_count <= c.total_entries, ErrorCode::InvalidWinnerCount);
+    // The root binds the operator: once posted it can't be swapped mid-payout
+    // or used to push the claim deadline back.
+    require!(c.winner_count == 0, ErrorCode::PayoutRootAlreadySet);
+    // The whole prize pool goes to winners; anything left out would end up
+    // in the creator's sweep.
+    require!(payout_total == c.prize_pool, ErrorCode::PayoutTotalMismatch);
 
     c.payout_root  = root;
     c.winner_count = winner_count;
+    c.payout_total = payout_total;
+    c.claim_deadline_slot = now.checked_add(CLAIM_PERIOD_SLOTS).ok_or(ErrorCode::NumericalOverflow)?;
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestStatus};
+use crate::state::participant::Participant;
+
+/// Permissionless: the player or any cranker can push a refund, but funds
+/// only ever go to a token account owned by the player.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct RefundEntry<'info> {
+
+    /// CHECK: pure identity; used only as seed for `participant` PDA.
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = pool_mint,
+        constraint = contest.status == ContestStatus::Cancelled @ErrorCode::ContestNotCancelled,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        mut,
+        seeds = [b"participant".as_ref(), &contest_id.to_le_bytes(), player.key().as_ref()],
+        bump,
+        has_one = player @ErrorCode::InvalidParticipant,
+    )]
+    pub participant: Box<Account<'info, Participant>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            contest_id.to_le_bytes().as_ref(),
+            pool_mint.key().as_ref()
+        ],
+        bump = contest.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA authority
+    #[account(
+        seeds = [b"vault_authority", &contest_id.to_le_bytes()],
+        bump = contest.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = player_token.owner == player.key() @ErrorCode::InvalidOwner,
+        constraint = player_token.mint == pool_mint.key() @ErrorCode::InvalidMint,
+    )]
+    pub player_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub pool_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct EntryRefunded {
+    pub contest_id: u64,
+    pub player:     Pubkey,
+    pub amount:     u64,
+}
+
+pub fn handler(ctx: Context<RefundEntry>, contest_id: u64) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let participant = &mut ctx.accounts.participant;
+
+    require!(!participant.refunded, ErrorCode::AlreadyRefunded);
+    participant.refunded = true;
+
+    if contest.entry_fee > 0 {
+        let contest_id_bytes = contest.contest_id.to_le_bytes();
+        let vault_authority_bump_bytes = &[contest.vault_authority_bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"vault_authority",
+            &contest_id_bytes,
+            vault_authority_bump_bytes,
+        ]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.pool_mint.to_account_info(),
+                    to: ctx.accounts.player_token.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            contest.entry_fee,
+            ctx.accounts.pool_mint.decimals,
+        )?;
+    }
+
+    emit!(EntryRefunded {
+        contest_id,
+        player: participant.player,
+        amount: contest.entry_fee,
+    });
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestStatus};
+use crate::state::sponsorship::Sponsorship;
+
+/// Permissionless: returns a sponsor's contribution once the contest is
+/// cancelled, only ever to a token account owned by the sponsor.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct RefundSponsor<'info> {
+
+    /// CHECK: pure identity; used only as seed for `sponsorship` PDA.
+    pub sponsor: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = pool_mint,
+        constraint = contest.status == ContestStatus::Cancelled @ErrorCode::ContestNotCancelled,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        mut,
+        seeds = [b"sponsorship", &contest_id.to_le_bytes()[..], sponsor.key().as_ref()],
+        bump = sponsorship.bump,
+        has_one = sponsor @ErrorCode::InvalidOwner,
+        constraint = !sponsorship.refunded @ErrorCode::AlreadyRefunded,
+    )]
+    pub sponsorship: Box<Account<'info, Sponsorship>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            contest_id.to_le_bytes().as_ref(),
+            pool_mint.key().as_ref()
+        ],
+        bump = contest.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA authority
+    #[account(
+        seeds = [b"vault_authority", &contest_id.to_le_bytes()],
+        bump = contest.vault_authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = sponsor_token.owner == sponsor.key() @ErrorCode::InvalidOwner,
+        constraint = sponsor_token.mint == pool_mint.key() @ErrorCode::InvalidMint,
+    )]
+    pub sponsor_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub pool_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct SponsorRefunded {
+    pub contest_id: u64,
+    pub sponsor:    Pubkey,
+    pub amount:     u64,
+}
+
+pub fn handler(ctx: Context<RefundSponsor>, contest_id: u64) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let sponsorship = &mut ctx.accounts.sponsorship;
+    sponsorship.refunded = true;
+
+    let contest_id_bytes = contest.contest_id.to_le_bytes();
+    let vault_authority_bump_bytes = &[contest.vault_authority_bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        &contest_id_bytes,
+        vault_authority_bump_bytes,
+    ]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.pool_mint.to_account_info(),
+                to: ctx.accounts.sponsor_token.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        sponsorship.amount,
+        ctx.accounts.pool_mint.decimals,
+    )?;
+
+    emit!(SponsorRefunded {
+        contest_id,
+        sponsor: sponsorship.sponsor,
+        amount: sponsorship.amount,
+    });
+    Ok(())
+}
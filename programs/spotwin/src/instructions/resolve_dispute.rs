@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::config::ProtocolConfig;
+use crate::state::contest::{Contest, ContestStatus};
+use crate::state::dispute::Dispute;
+
+/// Admin decision on a dispute. Upholding returns the bond and withdraws the
+/// answer key so the contest is back in `Locked` awaiting a corrected key;
+/// rejecting slashes the bond to the treasury. A stale dispute, against a key
+/// that has since been withdrawn, always gets its bond back. Either way the
+/// dispute account is closed to the disputer.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct ResolveDispute<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ErrorCode::Unauthorized,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        mut,
+        close = disputer,
+        seeds = [
+            b"dispute",
+            &contest_id.to_le_bytes()[..],
+            dispute.disputer.as_ref(),
+            &dispute.answer_key_round.to_le_bytes(),
+        ],
+        bump = dispute.bump,
+        constraint = !dispute.resolved @ErrorCode::DisputeAlreadyResolved,
+    )]
+    pub dispute: Box<Account<'info, Dispute>>,
+
+    /// CHECK: receives the bond back; pinned to `dispute.disputer`.
+    #[account(mut, address = dispute.disputer @ErrorCode::InvalidOwner)]
+    pub disputer: UncheckedAccount<'info>,
+
+    /// CHECK: receives slashed bonds; pinned to `config.treasury`.
+    #[account(mut, address = config.treasury @ErrorCode::InvalidOwner)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub contest_id: u64,
+    pub disputer:   Pubkey,
+    pub upheld:     bool,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>, contest_id: u64, upheld: bool) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let dispute = &mut ctx.accounts.dispute;
+    // `withdraw_answer_key` already dropped stale disputes from `open_disputes`.
+    let stale = contest.status != ContestStatus::AnswerKeyPosted
+        || dispute.answer_key_round != contest.answer_key_round;
+    let upheld = upheld && !stale;
+
+    let recipient = if upheld || stale {
+        ctx.accounts.disputer.to_account_info()
+    } else {
+        ctx.accounts.treasury.to_account_info()
+    };
+
+    let dispute_info = dispute.to_account_info();
+    **dispute_info.try_borrow_mut_lamports()? = dispute_info
+        .lamports()
+        .checked_sub(dispute.bond_lamports)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(dispute.bond_lamports)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    dispute.resolved = true;
+    dispute.upheld = upheld;
+
+    if !stale {
+        contest.open_disputes = contest.open_disputes.saturating_sub(1);
+        if upheld {
+            contest.upheld_questions |= dispute.disputed_questions;
+            contest.withdraw_answer_key();
+        }
+    }
+
+    emit!(DisputeResolved {
+        contest_id,
+        disputer: dispute.disputer,
+        upheld,
+    });
+    Ok(())
+}
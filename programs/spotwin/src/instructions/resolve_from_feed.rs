@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::instructions::post_answer_key::AnswerKeyPosted;
+use crate::state::contest::{Contest, ContestStatus, ResolutionMode};
+use crate::state::feed::{FeedBindings, FeedResult};
+
+/// Permissionless: builds the answer key of a `ResolutionMode::Feed` contest
+/// from its bound feeds once the contest has locked. Re-reading the same
+/// feeds can't correct an upheld dispute, so the questions it challenged are
+/// voided instead.
+///
+/// Remaining accounts: the bound feed of every question, in question order,
+/// followed by the tiebreaker feed when the contest has a tiebreaker.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct ResolveFromFeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = contest.status == ContestStatus::Locked @ErrorCode::ContestNotLocked,
+        constraint = contest.resolution_mode == ResolutionMode::Feed @ErrorCode::WrongResolutionMode,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        seeds = [b"feeds", &contest_id.to_le_bytes()[..]],
+        bump = feed_bindings.bump,
+    )]
+    pub feed_bindings: Box<Account<'info, FeedBindings>>,
+}
+
+pub fn handler(
+    ctx: Context<ResolveFromFeed>,
+    contest_id: u64,
+) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let contest = &mut ctx.accounts.contest;
+    let bindings = &ctx.accounts.feed_bindings;
+    let feed_program = contest.feed_program;
+
+    let lock_slot = contest.lock_slot;
+    require!(now >= lock_slot, ErrorCode::LockSlotNotReached);
+
+    let tiebreaker_accounts = contest.has_tiebreaker as usize;
+    require!(
+        ctx.remaining_accounts.len() == bindings.questions.len() + tiebreaker_accounts,
+        ErrorCode::InvalidArguments
+    );
+
+    // Only results published after the lock can settle the contest; anything
+    // older was visible to players while they could still change answers.
+    let read = |info: &AccountInfo, expected: &Pubkey| -> Result<i64> {
+        require_keys_eq!(info.key(), *expected, ErrorCode::InvalidFeed);
+        let result = FeedResult::read(info, &feed_program)?;
+        require!(result.publish_slot >= lock_slot, ErrorCode::FeedStale);
+        Ok(result.value)
+    };
+
+    let width = contest.answer_width();
+    let void_mask = contest.upheld_questions;
+    let mut answer_key = 0u64;
+    for (i, (binding, info)) in bindings
+        .questions
+        .iter()
+        .zip(ctx.remaining_accounts.iter())
+        .enumerate()
+    {
+        if void_mask & (1 << i) != 0 {
+            continue;
+        }
+        if read(info, &binding.feed)? > binding.threshold {
+            answer_key |= 1 << (i as u32 * width);
+        }
+    }
+
+    let tiebreaker_result = if contest.has_tiebreaker {
+        let info = &ctx.remaining_accounts[bindings.questions.len()];
+        Some(read(info, &bindings.tiebreaker_feed)?)
+    } else {
+        None
+    };
+
+    contest.post_answer_key(answer_key, void_mask, tiebreaker_result, now)?;
+
+    emit!(AnswerKeyPosted {
+        contest_id,
+        answer_key,
+        void_mask,
+        tiebreaker_result: contest.tiebreaker_result,
+    });
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CLAIM_PERIOD_SLOTS;
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestStatus, PayoutScheme};
+use crate::state::leaderboard::{Leaderboard, LeaderboardEntry};
+use crate::state::participant::Participant;
+
+/// Permissionless: anyone may score a participant once the answer key is
+/// posted, so winner selection can be audited on-chain. The cranker pays for
+/// the contest's leaderboard the first time it is needed. Scoring the last
+/// participant commits the payouts of a computed payout scheme.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct ScoreParticipant<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = contest.status == ContestStatus::AnswerKeyPosted @ErrorCode::ContestNotAnswerKeyPosted,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    /// CHECK: pure identity; used only as seed for `participant` PDA.
+    pub player: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"participant".as_ref(), &contest_id.to_le_bytes(), player.key().as_ref()],
+        bump,
+        has_one = player @ErrorCode::InvalidParticipant,
+        constraint = !participant.scored @ErrorCode::AlreadyScored,
+    )]
+    pub participant: Box<Account<'info, Participant>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = Leaderboard::LEN,
+        seeds = [b"leaderboard", &contest_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ParticipantScored {
+    pub contest_id: u64,
+    pub player:     Pubkey,
+    pub score:      u16,
+}
+
+pub fn handler(ctx: Context<ScoreParticipant>, contest_id: u64) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let p = &mut ctx.accounts.participant;
+
+    require!(contest.answer_key_final(Clock::get()?.slot), ErrorCode::AnswerKeyNotFinal);
+
+    p.score = contest.score(p);
+    p.scored = true;
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.contest_id = contest_id;
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.insert(LeaderboardEntry {
+        player: p.player,
+        score: p.score,
+        tiebreak: contest.tiebreak(p),
+    });
+
+    contest.scored_count = contest
+        .scored_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    contest.total_score = contest
+        .total_score
+        .checked_add(p.score as u64)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    if contest.is_perfect(p) {
+        contest.perfect_count = contest
+            .perfect_count
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+    }
+
+    if contest.scored_count == contest.total_entries && contest.payout_scheme != PayoutScheme::MerkleRoot {
+        let now = Clock::get()?.slot;
+        contest.payout_total = contest.scheme_payout_total(leaderboard);
+        contest.claim_deadline_slot = now.checked_add(CLAIM_PERIOD_SLOTS).ok_or(ErrorCode::NumericalOverflow)?;
+    }
+
+    emit!(ParticipantScored {
+        contest_id,
+        player: p.player,
+        score: p.score,
+    });
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::error::ErrorCode;
+use crate::merkle;
 
-use crate::state::contest::{Contest, ContestStatus};
+use crate::state::contest::{Contest, ContestStatus, PayoutScheme};
+use crate::state::participant::Participant;
 
 #[derive(Accounts)]
 #[instruction(contest_id: u64)]
@@ -17,8 +19,8 @@ pub struct SendBatch<'info> {
         seeds = [b"contest", &contest_id.to_le_bytes()[..]],
         bump = contest.contest_bump,
         constraint = contest.status == ContestStatus::AnswerKeyPosted @ErrorCode::ContestNotAnswerKeyPosted,
-        constraint = contest.answer_key != 0
-            @ ErrorCode::AnswerKeyNotSet,
+        constraint = contest.payout_scheme == PayoutScheme::MerkleRoot
+            @ ErrorCode::WrongPayoutScheme,
         has_one = creator
     )]
     pub contest: Box<Account<'info, Contest>>,
@@ -51,22 +53,34 @@ pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, SendBatch<'info>>, 
     contest_id: u64,
     winners: Vec<Pubkey>,
-    amounts: Vec<u64>
+    amounts: Vec<u64>,
+    proofs: Vec<Vec<[u8; 32]>>,
 ) -> Result<()> {
 
     require!(winners.len() == amounts.len(), ErrorCode::InvalidWinnerCount);
+    require!(winners.len() == proofs.len(), ErrorCode::InvalidArguments);
     require!(
         !winners.is_empty(),
         ErrorCode::EmptyBatch
     );
+    require!(
+        ctx.remaining_accounts.len() == winners.len() * 2,
+        ErrorCode::InvalidArguments
+    );
     
     let contest = &mut ctx.accounts.contest;
     require!(
         contest.contest_id == contest_id,
         ErrorCode::InvalidContestId
     );
+    require!(contest.winner_count > 0, ErrorCode::PayoutRootNotSet);
+    require!(contest.answer_key_final(Clock::get()?.slot), ErrorCode::AnswerKeyNotFinal);
+
+    let ata_start = winners.len();
+    let mut total_paid: u64 = 0;
 
-    // Validate all participants first
+    // Validate all participants, recipients and proofs first, recording each
+    // payout on its participant so retries and overlapping batches fail.
     for (i, winner) in winners.iter().enumerate() {
         let aact = &ctx.remaining_accounts[i];
         let expected = Pubkey::find_program_address(
@@ -78,14 +92,37 @@ pub fn handler<'a, 'b, 'c, 'info>(
             ctx.program_id
         ).0;
         require!(
-            aact.key() == expected,
+            aact.key() == expected && aact.is_writable,
             ErrorCode::InvalidParticipant
         );
+
+        let ata_info = &ctx.remaining_accounts[ata_start + i];
+        require!(
+            *ata_info.owner == ctx.accounts.token_program.key(),
+            ErrorCode::InvalidOwner
+        );
+        let ata = TokenAccount::try_deserialize(&mut &ata_info.try_borrow_data()?[..])?;
+        require!(ata.owner == *winner, ErrorCode::InvalidOwner);
+        require!(ata.mint == ctx.accounts.pool_mint.key(), ErrorCode::InvalidMint);
+
+        let leaf = merkle::payout_leaf(contest.contest_id, winner, amounts[i]);
+        require!(
+            merkle::verify(&proofs[i], &contest.payout_root, leaf),
+            ErrorCode::InvalidProof
+        );
+
+        require!(amounts[i] > 0, ErrorCode::InvalidArguments);
+        let mut participant = Participant::try_deserialize(&mut &aact.try_borrow_data()?[..])?;
+        require!(participant.paid_amount == 0, ErrorCode::AlreadyPaid);
+        participant.paid_amount = amounts[i];
+        participant.try_serialize(&mut &mut aact.try_borrow_mut_data()?[..])?;
+
+        total_paid = total_paid.checked_add(amounts[i]).ok_or(ErrorCode::NumericalOverflow)?;
     }
 
-    let ata_start = winners.len();
-    let mut total_paid: u64 = 0;
-    
+    let new_paid_so_far = contest.paid_so_far.checked_add(total_paid).ok_or(ErrorCode::NumericalOverflow)?;
+    require!(new_paid_so_far <= contest.payout_total, ErrorCode::PayoutExceedsCommitted);
+
     // Process each transfer
     for (i, amount) in amounts.into_iter().enumerate() {
         // Get the token account to transfer to
@@ -116,10 +153,8 @@ pub fn handler<'a, 'b, 'c, 'info>(
             amount,
             ctx.accounts.pool_mint.decimals,
         )?;
-        
-        total_paid = total_paid.checked_add(amount).ok_or(ErrorCode::NumericalOverflow)?;
     }
     
-    contest.paid_so_far = contest.paid_so_far.checked_add(total_paid).ok_or(ErrorCode::NumericalOverflow)?;
+    contest.paid_so_far = new_paid_so_far;
     Ok(())
-}
\ No newline at end of file
+}
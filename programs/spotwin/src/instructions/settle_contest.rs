@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+use crate::state::contest::{Contest, ContestStatus};
+
+/// Permissionless: anyone may settle once every committed payout has gone out
+/// or the claim window has closed.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct SettleContest<'info> {
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = contest.status == ContestStatus::AnswerKeyPosted @ErrorCode::ContestNotAnswerKeyPosted,
+        constraint = contest.payouts_committed() @ErrorCode::PayoutsNotCommitted,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+}
+
+#[event]
+pub struct ContestSettled {
+    pub contest_id:   u64,
+    pub paid_so_far:  u64,
+    pub payout_total: u64,
+    pub slot:         u64,
+}
+
+pub fn handler(ctx: Context<SettleContest>, contest_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        contest.paid_so_far == contest.payout_total || clock.slot >= contest.claim_deadline_slot,
+        ErrorCode::SettlementNotReady
+    );
+
+    contest.status = ContestStatus::Settled;
+
+    emit!(ContestSettled {
+        contest_id,
+        paid_so_far: contest.paid_so_far,
+        payout_total: contest.payout_total,
+        slot: clock.slot,
+    });
+    Ok(())
+}
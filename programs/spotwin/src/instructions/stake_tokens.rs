@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::state::config::ProtocolConfig;
 use crate::state::stake::StakeAccount;
-use crate::constants::LOCK_PERIOD_SLOTS;
 use anchor_spl::token::{self, Transfer, TokenAccount, Token};
 
 #[derive(Accounts)]
@@ -11,6 +11,12 @@ pub struct StakeTokens<'info> {
     #[account(mut, signer)]
     /// CHECK: backend sponsor – pays rent & tx fee
     pub fee_payer: Signer<'info>, 
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
     
     #[account(
         init_if_needed,
@@ -49,7 +55,7 @@ pub fn handler(
     ctx: Context<StakeTokens>,
     amount: u64,
 ) -> Result<()> {
-    let now = Clock::get()?.slot;
+    let clock = Clock::get()?;
 
     let cpi_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -64,7 +70,11 @@ pub fn handler(
     let acct = &mut ctx.accounts.stake_acct;
     acct.owner      = ctx.accounts.staker.key();
     acct.amount     = acct.amount.checked_add(amount).unwrap();
-    acct.start_slot = now;
+    acct.start_slot = clock.slot;
+    acct.start_unix_timestamp = clock.unix_timestamp;
+    // Restaking restarts the lock, so it may as well pick up the current terms.
+    acct.lock_mode   = ctx.accounts.config.stake_lock_mode;
+    acct.lock_period = ctx.accounts.config.stake_lock_period;
 
     Ok(())
 }
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::instructions::post_answer_key::AnswerKeyPosted;
+use crate::state::contest::{Contest, ContestStatus, ResolutionMode};
+use crate::state::resolution_votes::{ResolutionSubmission, ResolutionVotes};
+
+/// A resolver's vote on the outcome of a `ResolutionMode::Quorum` contest.
+/// Resubmitting replaces the resolver's earlier vote for the round. The
+/// answer key is posted as soon as `threshold` submissions match.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct SubmitResolution<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        constraint = contest.status == ContestStatus::Locked @ErrorCode::ContestNotLocked,
+        constraint = contest.resolution_mode == ResolutionMode::Quorum @ErrorCode::WrongResolutionMode,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+
+    #[account(
+        mut,
+        seeds = [b"votes", &contest_id.to_le_bytes()[..]],
+        bump = resolution_votes.bump,
+        constraint = resolution_votes.is_resolver(&resolver.key()) @ErrorCode::NotAResolver,
+    )]
+    pub resolution_votes: Box<Account<'info, ResolutionVotes>>,
+}
+
+#[event]
+pub struct ResolutionSubmitted {
+    pub contest_id:        u64,
+    pub resolver:          Pubkey,
+    pub answer_key:        u64,
+    pub void_mask:         u32,
+    pub tiebreaker_result: Option<i64>,
+    pub matching:          u8,
+}
+
+#[event]
+pub struct ResolutionDisagreement {
+    pub contest_id:  u64,
+    pub resolver:    Pubkey,
+    pub conflicting: Vec<Pubkey>, // resolvers whose submission differs
+}
+
+pub fn handler(
+    ctx: Context<SubmitResolution>,
+    contest_id: u64,
+    answer_key: u64,
+    void_mask: u32,
+    tiebreaker_result: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let contest = &mut ctx.accounts.contest;
+    let votes = &mut ctx.accounts.resolution_votes;
+
+    contest.check_answer_key(answer_key, void_mask, tiebreaker_result)?;
+
+    let submission = ResolutionSubmission {
+        resolver: ctx.accounts.resolver.key(),
+        answer_key,
+        void_mask,
+        tiebreaker_result,
+    };
+    votes.submissions.retain(|s| s.resolver != submission.resolver);
+
+    let conflicting: Vec<Pubkey> = votes
+        .submissions
+        .iter()
+        .filter(|s| !s.matches(&submission))
+        .map(|s| s.resolver)
+        .collect();
+    // Counts this submission too.
+    let matching = (votes.submissions.len() - conflicting.len() + 1) as u8;
+
+    if !conflicting.is_empty() {
+        votes.disagreements = votes
+            .disagreements
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        emit!(ResolutionDisagreement {
+            contest_id,
+            resolver: submission.resolver,
+            conflicting,
+        });
+    }
+    emit!(ResolutionSubmitted {
+        contest_id,
+        resolver: submission.resolver,
+        answer_key,
+        void_mask,
+        tiebreaker_result,
+        matching,
+    });
+
+    if matching < votes.threshold {
+        votes.submissions.push(submission);
+        return Ok(());
+    }
+
+    votes.submissions.clear();
+    contest.post_answer_key(answer_key, void_mask, tiebreaker_result, now)?;
+
+    emit!(AnswerKeyPosted {
+        contest_id,
+        answer_key,
+        void_mask,
+        tiebreaker_result: contest.tiebreaker_result,
+    });
+    Ok(())
+}
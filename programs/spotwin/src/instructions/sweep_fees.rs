@@ -1,17 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, TransferChecked, TokenAccount, TokenInterface};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::BPS_DENOMINATOR;
 use crate::error::ErrorCode;
+use crate::state::config::ProtocolConfig;
 use crate::state::contest::{Contest, ContestStatus};
-use crate::state::stake::StakeAccount;
-use crate::constants::LOCK_PERIOD_SLOTS;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
-
 
+/// Sends a settled contest's residual (see `Contest::residual`) out of the
+/// vault, once: `protocol_fee_bps` of it to the treasury, the rest to the
+/// creator. Tokens sent to the vault any other way stay where they are.
 #[derive(Accounts)]
 #[instruction(contest_id: u64)]
 pub struct SweepFees<'info> {
 
-    #[account(mut, address = creator.key() @ ErrorCode::InvalidCreator)]
+    /// CHECK: only used to pin `creator_ata`; must match `contest.creator`.
     pub creator: UncheckedAccount<'info>,
 
     #[account(
@@ -20,7 +21,8 @@ pub struct SweepFees<'info> {
         bump = contest.contest_bump,
         has_one = pool_mint,
         has_one = creator,
-        constraint = contest.status == ContestStatus::Completed @ ErrorCode::ContestNotCompleted,
+        constraint = contest.status == ContestStatus::Settled @ ErrorCode::ContestNotSettled,
+        constraint = !contest.fees_swept @ ErrorCode::NothingToSweep,
     )]
     pub contest: Box<Account<'info, Contest>>,
 
@@ -45,37 +47,77 @@ pub struct SweepFees<'info> {
     )]
     pub creator_ata: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ ErrorCode::InvalidOwner,
+        constraint = treasury_ata.mint  == pool_mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
 
     pub pool_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
-    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<SweepVault>, contest_id: u64) -> Result<()> {
-    let balance = ctx.accounts.vault.amount;
-    require!(balance > 0, ErrorCode::NothingToSweep);
+#[event]
+pub struct FeesSwept {
+    pub contest_id:   u64,
+    pub creator_cut:  u64,
+    pub protocol_cut: u64,
+}
+
+pub fn handler(ctx: Context<SweepFees>, contest_id: u64) -> Result<()> {
+    let residual = ctx.accounts.contest.residual()?;
+    require!(residual > 0, ErrorCode::NothingToSweep);
+    ctx.accounts.contest.fees_swept = true;
+
+    let protocol_cut = (residual as u128)
+        .checked_mul(ctx.accounts.contest.protocol_fee_bps as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        / BPS_DENOMINATOR as u128;
+    let protocol_cut = protocol_cut as u64;
+    let creator_cut = residual - protocol_cut;
 
-    let cpi_accounts = TransferChecked {
-        from:      ctx.accounts.vault.to_account_info(),
-        mint:      ctx.accounts.pool_mint.to_account_info(),
-        to:        ctx.accounts.creator_ata.to_account_info(),
-        authority: ctx.accounts.vault_authority.to_account_info(),
-    };
+    let contest_id_bytes = contest_id.to_le_bytes();
     let seeds: &[&[&[u8]]] = &[&[
         b"vault_authority",
-        &contest_id.to_le_bytes(),
+        &contest_id_bytes,
         &[ctx.accounts.contest.vault_authority_bump],
     ]];
 
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts,
-            seeds,
-        ),
-        balance,
-        ctx.accounts.pool_mint.decimals,
-    )?;
+    for (to, amount) in [
+        (&ctx.accounts.treasury_ata, protocol_cut),
+        (&ctx.accounts.creator_ata, creator_cut),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let cpi_accounts = TransferChecked {
+            from:      ctx.accounts.vault.to_account_info(),
+            mint:      ctx.accounts.pool_mint.to_account_info(),
+            to:        to.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                seeds,
+            ),
+            amount,
+            ctx.accounts.pool_mint.decimals,
+        )?;
+    }
 
+    emit!(FeesSwept {
+        contest_id,
+        creator_cut,
+        protocol_cut,
+    });
     Ok(())
-}
\ No newline at end of file
+}
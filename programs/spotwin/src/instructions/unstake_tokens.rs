@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer, TokenAccount, Token};
+use crate::state::contest::LockMode;
 use crate::state::stake::StakeAccount;
-use crate::constants::LOCK_PERIOD_SLOTS;
 use crate::error::ErrorCode;
 
 #[derive(Accounts)]
@@ -9,7 +9,7 @@ use crate::error::ErrorCode;
 pub struct UnstakeTokens<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
-    
+
     #[account(
         mut,
         seeds  = [b"stake", staker.key().as_ref()],
@@ -42,12 +42,24 @@ pub fn handler(
     ctx: Context<UnstakeTokens>,
     amount: u64,
 ) -> Result<()> {
-    let now = Clock::get()?.slot;
+    let clock = Clock::get()?;
     let acct = &mut ctx.accounts.stake_acct;
+    let period = acct.lock_period;
 
-    let unlock_slot = acct.start_slot.checked_add(LOCK_PERIOD_SLOTS)
-    .ok_or(ErrorCode::NumericalOverflow)?;
-    require!(now >= unlock_slot, ErrorCode::StakeLocked);
+    let unlocked = match acct.lock_mode {
+        LockMode::Slot => {
+            let unlock_slot = acct.start_slot.checked_add(period)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+            clock.slot >= unlock_slot
+        }
+        LockMode::UnixTimestamp => {
+            let unlock_ts = i64::try_from(period).ok()
+            .and_then(|p| acct.start_unix_timestamp.checked_add(p))
+            .ok_or(ErrorCode::NumericalOverflow)?;
+            clock.unix_timestamp >= unlock_ts
+        }
+    };
+    require!(unlocked, ErrorCode::StakeLocked);
 
     require!(
         amount > 0 && amount <= acct.amount,
@@ -75,6 +87,7 @@ pub fn handler(
     acct.amount = acct.amount.checked_sub(amount).unwrap();
     if acct.amount == 0 {
         acct.start_slot = 0;
+        acct.start_unix_timestamp = 0;
     }
 
     Ok(())
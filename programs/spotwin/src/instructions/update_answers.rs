@@ -39,28 +39,43 @@ pub struct UpdateAnswers<'info> {
 }
 
 
-pub fn handler(ctx: Context<UpdateAnswers>, contest_id: u64, new_attempt_mask: u16, new_answer_bits: u16) -> Result<()> {
-    let now = Clock::get()?.slot;
+pub fn handler(
+    ctx: Context<UpdateAnswers>,
+    _contest_id: u64,
+    new_attempt_mask: u32,
+    new_answer_bits: u64,
+    tiebreaker_prediction: Option<i64>,
+) -> Result<()> {
+    let clock = Clock::get()?;
     let c = &mut ctx.accounts.contest;
 
-    require!(now < c.lock_slot, ErrorCode::ContestClosed);
+    require!(!c.is_past_lock(&clock), ErrorCode::ContestClosed);
     let participant = &mut ctx.accounts.participant;
     require!(
         participant.player == ctx.accounts.player.key(),
         ErrorCode::InvalidParticipant
     );
     require!(
-        new_attempt_mask.count_ones() == 9,
+        new_attempt_mask & !c.question_mask() == 0,
         ErrorCode::InvalidAttemptMask
     );
     require!(
-        new_answer_bits == (new_answer_bits & new_attempt_mask),
+        (c.min_attempts as u32..=c.max_attempts as u32).contains(&new_attempt_mask.count_ones()),
+        ErrorCode::InvalidAttemptMask
+    );
+    require!(
+        c.answers_valid(new_answer_bits, new_attempt_mask),
         ErrorCode::InvalidAnswerBits
     );
+    require!(
+        tiebreaker_prediction.is_some() == c.has_tiebreaker,
+        ErrorCode::InvalidTiebreaker
+    );
 
     let p = &mut ctx.accounts.participant;
     p.attempt_mask = new_attempt_mask;
     p.answer_bits = new_answer_bits;
+    p.tiebreaker_prediction = tiebreaker_prediction.unwrap_or_default();
     Ok(())
 }    
     
\ No newline at end of file
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::instructions::initialize_config::ConfigParams;
+use crate::state::config::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ErrorCode::Unauthorized,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: ConfigParams, new_admin: Option<Pubkey>) -> Result<()> {
+    params.validate()?;
+
+    let config = &mut ctx.accounts.config;
+    if let Some(new_admin) = new_admin {
+        config.admin = new_admin;
+    }
+    params.apply(config);
+
+    Ok(())
+}
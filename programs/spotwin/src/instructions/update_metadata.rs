@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::contest::{Contest, ContestMetadata, ContestStatus};
+
+/// Lets the creator fix the title, URI or question set until the first
+/// player joins; after that players rely on `questions_hash` not changing.
+#[derive(Accounts)]
+#[instruction(contest_id: u64)]
+pub struct UpdateMetadata<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contest", &contest_id.to_le_bytes()[..]],
+        bump = contest.contest_bump,
+        has_one = creator,
+        constraint = contest.status == ContestStatus::Open @ErrorCode::ContestClosed,
+        constraint = contest.total_entries == 0 @ErrorCode::MetadataFrozen,
+    )]
+    pub contest: Box<Account<'info, Contest>>,
+}
+
+#[event]
+pub struct ContestMetadataUpdated {
+    pub contest_id:     u64,
+    pub questions_hash: [u8; 32],
+}
+
+pub fn handler(ctx: Context<UpdateMetadata>, contest_id: u64, metadata: ContestMetadata) -> Result<()> {
+    metadata.validate()?;
+
+    let contest = &mut ctx.accounts.contest;
+    contest.metadata = metadata;
+
+    emit!(ContestMetadataUpdated {
+        contest_id,
+        questions_hash: contest.metadata.questions_hash,
+    });
+    Ok(())
+}
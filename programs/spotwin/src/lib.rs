@@ -4,12 +4,16 @@ pub mod state;
 pub mod instructions;
 pub mod error;
 pub mod constants;
+pub mod merkle;
 
 pub use state::*;
 pub use instructions::*;
-pub use error::*;
+pub use error::ErrorCode;
 pub use constants::*;
 
+use state::contest::ContestMetadata;
+use state::feed::QuestionFeed;
+
 declare_id!("9ADHDvAGodZkqeQm1XEEQimYaUM9LRJ2z7dp37zdSdDr");
 
 #[program]
@@ -21,28 +25,76 @@ pub mod spotwin {
         Ok(())
     }
 
-    pub fn create_contest(ctx: Context<CreateContest>, contest_id: u64, entry_fee: u64, lock_slot: u64) -> Result<()> {
-        create_contest::handler(ctx, contest_id, entry_fee, lock_slot)
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        initialize_config::handler(ctx, params)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams, new_admin: Option<Pubkey>) -> Result<()> {
+        update_config::handler(ctx, params, new_admin)
+    }
+
+    pub fn create_contest(ctx: Context<CreateContest>, contest_id: u64, params: CreateContestParams) -> Result<()> {
+        create_contest::handler(ctx, contest_id, params)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, contest_id: u64, metadata: ContestMetadata) -> Result<()> {
+        update_metadata::handler(ctx, contest_id, metadata)
     }
 
     pub fn join_contest(ctx: Context<JoinContest>, contest_id: u64) -> Result<()> {
         join_contest::handler(ctx, contest_id)
     }
 
-    pub fn update_answers(ctx: Context<UpdateAnswers>, contest_id: u64, new_answer_bits: u16, new_attempt_mask: u16) -> Result<()> {
-        update_answers::handler(ctx, contest_id, new_attempt_mask, new_answer_bits)
+    pub fn fund_prize_pool(ctx: Context<FundPrizePool>, contest_id: u64, amount: u64) -> Result<()> {
+        fund_prize_pool::handler(ctx, contest_id, amount)
+    }
+
+    pub fn update_answers(ctx: Context<UpdateAnswers>, contest_id: u64, new_answer_bits: u64, new_attempt_mask: u32, tiebreaker_prediction: Option<i64>) -> Result<()> {
+        update_answers::handler(ctx, contest_id, new_attempt_mask, new_answer_bits, tiebreaker_prediction)
     }
 
     pub fn lock_contest(ctx: Context<LockContest>, contest_id: u64) -> Result<()> {
         lock_contest::handler(ctx, contest_id)
     }
 
-    pub fn post_answer_key(ctx: Context<PostAnswerKey>, contest_id: u64, answer_key: u16) -> Result<()> {
-        post_answer_key::handler(ctx, contest_id, answer_key)
+    pub fn crank_lock(ctx: Context<CrankLock>, contest_id: u64) -> Result<()> {
+        crank_lock::handler(ctx, contest_id)
+    }
+
+    pub fn post_answer_key(ctx: Context<PostAnswerKey>, contest_id: u64, answer_key: u64, void_mask: u32, tiebreaker_result: Option<i64>, salt: [u8; 32]) -> Result<()> {
+        post_answer_key::handler(ctx, contest_id, answer_key, void_mask, tiebreaker_result, salt)
+    }
+
+    pub fn bind_question_feeds(ctx: Context<BindQuestionFeeds>, contest_id: u64, questions: Vec<QuestionFeed>, tiebreaker_feed: Option<Pubkey>) -> Result<()> {
+        bind_question_feeds::handler(ctx, contest_id, questions, tiebreaker_feed)
+    }
+
+    pub fn resolve_from_feed(ctx: Context<ResolveFromFeed>, contest_id: u64) -> Result<()> {
+        resolve_from_feed::handler(ctx, contest_id)
+    }
+
+    pub fn configure_resolvers(ctx: Context<ConfigureResolvers>, contest_id: u64, resolvers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        configure_resolvers::handler(ctx, contest_id, resolvers, threshold)
+    }
+
+    pub fn submit_resolution(ctx: Context<SubmitResolution>, contest_id: u64, answer_key: u64, void_mask: u32, tiebreaker_result: Option<i64>) -> Result<()> {
+        submit_resolution::handler(ctx, contest_id, answer_key, void_mask, tiebreaker_result)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>, contest_id: u64, disputed_questions: u32) -> Result<()> {
+        open_dispute::handler(ctx, contest_id, disputed_questions)
     }
 
-    pub fn post_payout_root(ctx: Context<PostPayoutRoot>, contest_id: u64, payout_root: [u8; 32], winner_count: u32) -> Result<()> {
-        post_payout_root::handler(ctx, contest_id, payout_root, winner_count)
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, contest_id: u64, upheld: bool) -> Result<()> {
+        resolve_dispute::handler(ctx, contest_id, upheld)
+    }
+
+    pub fn score_participant(ctx: Context<ScoreParticipant>, contest_id: u64) -> Result<()> {
+        score_participant::handler(ctx, contest_id)
+    }
+
+    pub fn post_payout_root(ctx: Context<PostPayoutRoot>, contest_id: u64, payout_root: [u8; 32], winner_count: u32, payout_total: u64) -> Result<()> {
+        post_payout_root::handler(ctx, contest_id, payout_root, winner_count, payout_total)
     }
 
     pub fn send_batch<'a, 'b, 'c, 'info>(
@@ -50,8 +102,37 @@ pub mod spotwin {
         contest_id: u64,
         winners: Vec<Pubkey>,
         amounts: Vec<u64>,
+        proofs: Vec<Vec<[u8; 32]>>,
     ) -> Result<()> {
-        send_batch::handler(ctx, contest_id, winners, amounts)
+        send_batch::handler(ctx, contest_id, winners, amounts, proofs)
+    }
+
+    pub fn claim_prize(ctx: Context<ClaimPrize>, contest_id: u64, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        claim_prize::handler(ctx, contest_id, amount, proof)
+    }
+
+    pub fn claim_scheme_prize(ctx: Context<ClaimSchemePrize>, contest_id: u64) -> Result<()> {
+        claim_scheme_prize::handler(ctx, contest_id)
+    }
+
+    pub fn settle_contest(ctx: Context<SettleContest>, contest_id: u64) -> Result<()> {
+        settle_contest::handler(ctx, contest_id)
+    }
+
+    pub fn cancel_contest(ctx: Context<CancelContest>, contest_id: u64) -> Result<()> {
+        cancel_contest::handler(ctx, contest_id)
+    }
+
+    pub fn refund_entry(ctx: Context<RefundEntry>, contest_id: u64) -> Result<()> {
+        refund_entry::handler(ctx, contest_id)
+    }
+
+    pub fn refund_sponsor(ctx: Context<RefundSponsor>, contest_id: u64) -> Result<()> {
+        refund_sponsor::handler(ctx, contest_id)
+    }
+
+    pub fn sweep_fees(ctx: Context<SweepFees>, contest_id: u64) -> Result<()> {
+        sweep_fees::handler(ctx, contest_id)
     }
 
     pub fn initialize_stake(
@@ -67,6 +148,10 @@ pub mod spotwin {
     pub fn unstake_tokens(ctx: Context<UnstakeTokens>, amount: u64) -> Result<()> {
         unstake_tokens::handler(ctx, amount)
     }
+
+    pub fn migrate_stake(ctx: Context<MigrateStake>) -> Result<()> {
+        migrate_stake::handler(ctx)
+    }
 }
 
 #[derive(Accounts)]
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain separators so a leaf can never be replayed as an inner node.
+pub const LEAF_PREFIX: u8 = 0x00;
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Payout tree leaf:
+///
+/// `sha256(0x00 || contest_id as u64 LE || player (32 bytes) || amount as u64 LE)`
+pub fn payout_leaf(contest_id: u64, player: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_PREFIX],
+        &contest_id.to_le_bytes(),
+        player.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Inner node: `sha256(0x01 || min(a, b) || max(a, b))`.
+/// Children are sorted so proofs don't need to carry left/right flags.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[NODE_PREFIX], lo, hi]).to_bytes()
+}
+
+/// Folds `proof` (sibling hashes, leaf level first) over `leaf` and compares with `root`.
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ALLOWED_MINTS;
+use crate::state::contest::LockMode;
+
+/// Protocol-wide settings, a singleton PDA at `[b"config"]`.
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,           // owner of the treasury token accounts
+    pub protocol_fee_bps: u16,      // share of each swept residual sent to the treasury
+    pub max_rake_bps: u16,          // cap on the creator rake a contest may declare
+    pub allowed_mints: Vec<Pubkey>, // pool mints contests may be created with
+    pub stake_mint: Pubkey,
+    pub stake_lock_mode: LockMode,
+    pub stake_lock_period: u64,     // slots or seconds, per stake_lock_mode
+    pub dispute_bond_lamports: u64, // posted by open_dispute, slashed if rejected
+    pub feed_program: Pubkey,       // owner of feed accounts; new Feed contests snapshot it
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize =
+        8  +                            // Anchor account discriminator
+        32 + 32 + 2 + 2 +               // admin, treasury, protocol_fee_bps, max_rake_bps
+        4 + 32 * MAX_ALLOWED_MINTS +    // allowed_mints
+        32 + 1 + 8 +                    // stake_mint, stake_lock_mode, stake_lock_period
+        8 +                             // dispute_bond_lamports
+        32 +                            // feed_program
+        1;                              // bump
+
+    pub fn is_mint_allowed(&self, mint: &Pubkey) -> bool {
+        self.allowed_mints.contains(mint)
+    }
+}
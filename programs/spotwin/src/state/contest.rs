@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::{BPS_DENOMINATOR, MAX_METADATA_URI_LEN, MAX_QUESTIONS, MAX_TIERS, MAX_TITLE_LEN};
+use crate::error::ErrorCode;
+use crate::state::leaderboard::Leaderboard;
+use crate::state::participant::Participant;
 
 /// Contest account
 #[account]
@@ -7,14 +13,48 @@ pub struct Contest {
     pub contest_id: u64,
     pub pool_mint: Pubkey, // USDC or SPOT
     pub entry_fee: u64,
-    pub lock_slot: u64,
+    pub lock_slot: u64,            // UnixTimestamp mode: the slot the contest locked in
 
     pub status: ContestStatus,
     pub total_entries: u32,
-    pub answer_key: u16,
+    pub answer_key: u64,           // packed like Participant::answer_bits
     pub payout_root:  [u8; 32], 
     pub winner_count: u32,
+    pub payout_total: u64, // sum of all amounts committed to in payout_root
     pub paid_so_far:  u64,
+    pub claim_deadline_slot: u64, // settle_contest may close unpaid claims after this
+    pub protocol_fee_bps: u16,    // snapshot of the config fee at creation
+    pub rake_bps: u16,            // creator's share of the entry pool
+    pub prize_pool: u64,          // winners' share, fixed when the contest locks
+    pub max_entries: u32,         // 0 = no cap
+    pub min_entries: u32,         // locking below this cancels the contest
+    pub scored_count: u32,        // participants scored by score_participant
+    pub payout_scheme: PayoutScheme,
+    pub tier_bps: [u16; MAX_TIERS], // Tiered: prize_pool share per leaderboard rank
+    pub perfect_count: u32,       // scored participants with every attempt correct
+    pub total_score: u64,         // sum of all scores, for Proportional
+    pub sponsored_amount: u64,    // fund_prize_pool deposits, added to the prize pool rake-free
+    pub question_count: u8,       // questions are bits 0..question_count of the masks
+    pub min_attempts: u8,
+    pub max_attempts: u8,
+    pub option_count: u8,         // choices per question, 2 = yes/no
+    pub question_weights: [u8; MAX_QUESTIONS], // points per correct answer
+    pub has_tiebreaker: bool,     // players predict a number, e.g. total goals
+    pub tiebreaker_result: i64,   // actual value, set with the answer key
+    pub void_mask: u32,           // questions that count neither for nor against anyone
+    pub dispute_period_slots: u64, // challenge window after the answer key is posted
+    pub answer_key_slot: u64,     // when the current answer key was posted
+    pub answer_key_round: u32,    // answer keys posted so far; disputes name the one they challenge
+    pub open_disputes: u32,
+    pub resolution_mode: ResolutionMode,
+    pub lock_mode: LockMode,
+    pub lock_unix_timestamp: i64, // UnixTimestamp mode deadline
+    pub metadata: ContestMetadata,
+    pub answer_commitment: [u8; 32], // sha256(answer_key LE || tiebreaker_result LE || salt); zero = none
+    pub fees_swept: bool,
+    pub feed_program: Pubkey,     // snapshot of the config's feed program at creation
+    pub resolution_ready: bool,   // feeds bound / resolvers configured; gates joining
+    pub upheld_questions: u32,    // challenged by upheld disputes; voidable on committed keys, voided on feed ones
 
     pub contest_bump: u8,
     pub vault_bump:   u8,
@@ -30,10 +70,532 @@ pub enum ContestStatus {
     Cancelled,
 }
 
+/// Bits per question in packed answers: the smallest width that holds
+/// `option_count - 1`. Question `i` occupies bits `i * w .. (i + 1) * w`,
+/// so yes/no contests keep one bit per question.
+pub fn answer_width(option_count: u8) -> u32 {
+    u8::BITS - (option_count - 1).leading_zeros()
+}
+
+/// What the contest is about, for wallets and explorers. Frozen once the
+/// first player joins.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ContestMetadata {
+    pub title: String,
+    pub metadata_uri: String,     // off-chain JSON with the questions and artwork
+    pub questions_hash: [u8; 32], // sha256 of the canonical question set the URI serves
+}
+
+impl ContestMetadata {
+    pub const LEN: usize =
+        4 + MAX_TITLE_LEN +
+        4 + MAX_METADATA_URI_LEN +
+        32;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.title.len() <= MAX_TITLE_LEN && self.metadata_uri.len() <= MAX_METADATA_URI_LEN,
+            ErrorCode::MetadataTooLong
+        );
+        Ok(())
+    }
+}
+
+/// Which clock a deadline is measured against.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// `Clock::slot`; cheap to reason about but drifts from wall time.
+    Slot,
+    /// `Clock::unix_timestamp`, for deadlines scheduled by kickoff time.
+    UnixTimestamp,
+}
+
+/// Who produces the answer key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionMode {
+    /// The creator posts it with `post_answer_key`.
+    Manual,
+    /// `resolve_from_feed` derives it from the accounts in `FeedBindings`,
+    /// which must be bound before anyone can join.
+    Feed,
+    /// An M-of-N resolver set agrees on it via `submit_resolution`. The set
+    /// must be configured before anyone can join.
+    Quorum,
+}
+
+/// How winners' entitlements are determined.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutScheme {
+    /// Operator-posted `payout_root`, paid via `send_batch` / `claim_prize`.
+    MerkleRoot,
+    /// Leaderboard rank 0 takes the whole prize pool.
+    WinnerTakeAll,
+    /// Prize pool split equally among participants with every attempt
+    /// correct; split as `Proportional` when nobody is perfect.
+    EqualSplitPerfect,
+    /// Leaderboard rank `i` takes `tier_bps[i]` parts of the prize pool. Tiers
+    /// nobody placed in are dropped and the rest scaled up to the whole pool.
+    Tiered,
+    /// Prize pool split pro rata by score, or equally among all participants
+    /// when nobody scored.
+    Proportional,
+}
+
 impl Contest {
     pub const LEN: usize =
         8  +            // Anchor account discriminator
         32 + 8 + 32 + 8 + 8 +          // creator, contest_id, pool_mint, entry_fee, lock_slot
-        1  + 4 + 2  + 32 + 4 + 8 +     // status, total_entries, answer_key, root, winner_count, paid
+        1  + 4 + 8  + 32 + 4 + 8 +     // status, total_entries, answer_key, root, winner_count, paid
+        8 + 8 + 2 +                    // payout_total, claim_deadline_slot, protocol_fee_bps
+        2 + 8 +                        // rake_bps, prize_pool
+        4 + 4 + 4 +                    // max_entries, min_entries, scored_count
+        1 + 2 * MAX_TIERS + 4 + 8 +    // payout_scheme, tier_bps, perfect_count, total_score
+        8 +                            // sponsored_amount
+        1 + 1 + 1 + 1 +                // question_count, min/max_attempts, option_count
+        MAX_QUESTIONS +                // question_weights
+        1 + 8 + 4 +                    // has_tiebreaker, tiebreaker_result, void_mask
+        8 + 8 + 4 + 4 +                // dispute_period_slots, answer_key_slot, answer_key_round, open_disputes
+        1 +                            // resolution_mode
+        1 + 8 +                        // lock_mode, lock_unix_timestamp
+        ContestMetadata::LEN +         // metadata
+        32 + 1 +                       // answer_commitment, fees_swept
+        32 + 1 +                       // feed_program, resolution_ready
+        4 +                            // upheld_questions
         1 + 1 + 1;                     // contest_bump, vault_bump, vault_authority_bump
+
+    /// Bits of the answer and attempt masks that map to a question.
+    pub fn question_mask(&self) -> u32 {
+        u32::MAX >> (MAX_QUESTIONS - self.question_count as usize)
+    }
+
+    pub fn answer_width(&self) -> u32 {
+        answer_width(self.option_count)
+    }
+
+    /// The chosen option for `question` in a packed answer word.
+    pub fn answer_at(&self, packed: u64, question: u32) -> u64 {
+        let w = self.answer_width();
+        (packed >> (question * w)) & ((1u64 << w) - 1)
+    }
+
+    /// Every question in `mask` holds a valid option and every other field,
+    /// including bits past the last question, is zero.
+    pub fn answers_valid(&self, packed: u64, mask: u32) -> bool {
+        let w = self.answer_width();
+        let mut expected_zero = packed;
+        for q in 0..self.question_count as u32 {
+            let option = self.answer_at(packed, q);
+            if mask & (1 << q) != 0 {
+                if option >= self.option_count as u64 {
+                    return false;
+                }
+                expected_zero &= !(((1u64 << w) - 1) << (q * w));
+            }
+        }
+        expected_zero == 0
+    }
+
+    /// `packed` with the fields of the questions in `void_mask` cleared.
+    pub fn without_voided(&self, packed: u64, void_mask: u32) -> u64 {
+        let w = self.answer_width();
+        (0..self.question_count as u32)
+            .filter(|q| void_mask & (1 << q) != 0)
+            .fold(packed, |key, q| key & !(((1u64 << w) - 1) << (q * w)))
+    }
+
+    /// Attempted questions that weren't voided.
+    fn attempted(&self, p: &Participant) -> impl Iterator<Item = u32> + '_ {
+        let mask = p.attempt_mask & !self.void_mask;
+        (0..self.question_count as u32).filter(move |q| mask & (1 << q) != 0)
+    }
+
+    /// Sum of `question_weights` over attempted questions answered as in `answer_key`.
+    pub fn score(&self, p: &Participant) -> u16 {
+        self.attempted(p)
+            .filter(|q| self.answer_at(p.answer_bits, *q) == self.answer_at(self.answer_key, *q))
+            .map(|q| self.question_weights[q as usize] as u16)
+            .sum()
+    }
+
+    /// Points `p` would have scored with every attempt correct.
+    pub fn max_score(&self, p: &Participant) -> u16 {
+        self.attempted(p)
+            .map(|q| self.question_weights[q as usize] as u16)
+            .sum()
+    }
+
+    /// Every attempted question was answered correctly. Only meaningful once scored.
+    pub fn is_perfect(&self, p: &Participant) -> bool {
+        let max_score = self.max_score(p);
+        max_score > 0 && p.score == max_score
+    }
+
+    /// Secondary ranking key, lower is better: distance from the tiebreaker
+    /// result when the contest has one, otherwise the join slot.
+    pub fn tiebreak(&self, p: &Participant) -> u64 {
+        if self.has_tiebreaker {
+            p.tiebreaker_prediction.abs_diff(self.tiebreaker_result)
+        } else {
+            p.joined_slot
+        }
+    }
+
+    pub fn in_dispute_window(&self, now: u64) -> bool {
+        self.status == ContestStatus::AnswerKeyPosted
+            && now < self.answer_key_slot.saturating_add(self.dispute_period_slots)
+    }
+
+    /// The answer key can no longer be challenged: the window has passed and
+    /// every dispute is resolved. Scoring and payouts wait for this.
+    pub fn answer_key_final(&self, now: u64) -> bool {
+        self.status == ContestStatus::AnswerKeyPosted
+            && !self.in_dispute_window(now)
+            && self.open_disputes == 0
+    }
+
+    /// Checks a proposed outcome against the contest's question layout.
+    pub fn check_answer_key(
+        &self,
+        answer_key: u64,
+        void_mask: u32,
+        tiebreaker_result: Option<i64>,
+    ) -> Result<()> {
+        require!(
+            void_mask & !self.question_mask() == 0,
+            ErrorCode::InvalidVoidMask
+        );
+        // Voided questions have no answer, so their fields must be left zero.
+        require!(
+            self.answers_valid(answer_key, self.question_mask() & !void_mask),
+            ErrorCode::InvalidAnswerKey
+        );
+        require!(
+            tiebreaker_result.is_some() == self.has_tiebreaker,
+            ErrorCode::InvalidTiebreaker
+        );
+        Ok(())
+    }
+
+    /// Validates and records the outcome, starting the dispute window.
+    pub fn post_answer_key(
+        &mut self,
+        answer_key: u64,
+        void_mask: u32,
+        tiebreaker_result: Option<i64>,
+        now: u64,
+    ) -> Result<()> {
+        self.check_answer_key(answer_key, void_mask, tiebreaker_result)?;
+
+        self.answer_key = answer_key;
+        self.void_mask = void_mask;
+        self.tiebreaker_result = tiebreaker_result.unwrap_or_default();
+        self.answer_key_slot = now;
+        self.answer_key_round = self
+            .answer_key_round
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        self.status = ContestStatus::AnswerKeyPosted;
+        Ok(())
+    }
+
+    pub fn has_answer_commitment(&self) -> bool {
+        self.answer_commitment != [0u8; 32]
+    }
+
+    /// Whether the outcome and `salt` open the commitment made at creation.
+    /// The tiebreaker result is committed too (0 without a tiebreaker), since
+    /// it ranks tied players just as the answers do.
+    pub fn opens_commitment(&self, answer_key: u64, tiebreaker_result: i64, salt: &[u8; 32]) -> bool {
+        hashv(&[&answer_key.to_le_bytes(), &tiebreaker_result.to_le_bytes(), salt]).to_bytes()
+            == self.answer_commitment
+    }
+
+    /// Whether the entry deadline has passed under the contest's `lock_mode`.
+    pub fn is_past_lock(&self, clock: &Clock) -> bool {
+        match self.lock_mode {
+            LockMode::Slot => clock.slot >= self.lock_slot,
+            LockMode::UnixTimestamp => clock.unix_timestamp >= self.lock_unix_timestamp,
+        }
+    }
+
+    /// Undoes `post_answer_key` after an upheld dispute. Any other disputes
+    /// against the withdrawn key go stale and no longer hold up payouts.
+    pub fn withdraw_answer_key(&mut self) {
+        self.open_disputes = 0;
+        self.answer_key = 0;
+        self.void_mask = 0;
+        self.tiebreaker_result = 0;
+        self.answer_key_slot = 0;
+        self.status = ContestStatus::Locked;
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.max_entries != 0 && self.total_entries >= self.max_entries
+    }
+
+    /// Moves the contest to `Locked` and fixes the prize-pool / rake split.
+    /// Rake only applies to entry fees; sponsor funds go to winners in full.
+    /// A contest short of `min_entries` is cancelled instead so every player
+    /// can `refund_entry`.
+    pub fn lock(&mut self, now: u64) -> Result<()> {
+        if self.lock_mode == LockMode::UnixTimestamp {
+            self.lock_slot = now;
+        }
+        if self.total_entries < self.min_entries {
+            self.status = ContestStatus::Cancelled;
+            return Ok(());
+        }
+
+        let gross = self.entry_fee
+            .checked_mul(self.total_entries as u64)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let rake = (gross as u128)
+            .checked_mul(self.rake_bps as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?
+            / BPS_DENOMINATOR as u128;
+
+        self.prize_pool = (gross - rake as u64)
+            .checked_add(self.sponsored_amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        self.status = ContestStatus::Locked;
+        Ok(())
+    }
+
+    /// `prize_pool * parts / whole`, rounded down.
+    fn pool_share(&self, parts: u64, whole: u64) -> u64 {
+        (self.prize_pool as u128 * parts as u128 / whole as u128) as u64
+    }
+
+    /// Tier weights of the ranks someone actually placed in.
+    fn awarded_tiers(&self, leaderboard: &Leaderboard) -> &[u16] {
+        &self.tier_bps[..leaderboard.entries.len().min(MAX_TIERS)]
+    }
+
+    fn awarded_tier_total(&self, leaderboard: &Leaderboard) -> u64 {
+        self.awarded_tiers(leaderboard).iter().map(|bps| *bps as u64).sum()
+    }
+
+    /// Proportional share of `p`, or an equal share when nobody scored.
+    fn pro_rata_entitlement(&self, p: &Participant) -> u64 {
+        if self.total_score == 0 {
+            self.prize_pool / self.scored_count.max(1) as u64
+        } else {
+            self.pool_share(p.score as u64, self.total_score)
+        }
+    }
+
+    /// Sum of every `pro_rata_entitlement`: exact for the equal split, an
+    /// upper bound otherwise since pro rata rounding may leave dust.
+    fn pro_rata_total(&self) -> u64 {
+        if self.total_score == 0 {
+            self.prize_pool / self.scored_count.max(1) as u64 * self.scored_count as u64
+        } else {
+            self.prize_pool
+        }
+    }
+
+    /// What a scored participant is owed under a computed payout scheme.
+    /// Every scheme hands out the whole prize pool, up to rounding dust.
+    pub fn scheme_entitlement(&self, p: &Participant, leaderboard: &Leaderboard) -> u64 {
+        match self.payout_scheme {
+            PayoutScheme::MerkleRoot => 0,
+            PayoutScheme::WinnerTakeAll => match leaderboard.rank_of(&p.player) {
+                Some(0) => self.prize_pool,
+                _ => 0,
+            },
+            PayoutScheme::Tiered => match leaderboard.rank_of(&p.player) {
+                Some(rank) if rank < MAX_TIERS => self.pool_share(
+                    self.tier_bps[rank] as u64,
+                    self.awarded_tier_total(leaderboard),
+                ),
+                _ => 0,
+            },
+            PayoutScheme::EqualSplitPerfect if self.perfect_count > 0 => {
+                if self.is_perfect(p) {
+                    self.prize_pool / self.perfect_count as u64
+                } else {
+                    0
+                }
+            }
+            PayoutScheme::EqualSplitPerfect | PayoutScheme::Proportional => {
+                self.pro_rata_entitlement(p)
+            }
+        }
+    }
+
+    /// Upper bound on everything `scheme_entitlement` can hand out once every
+    /// participant is scored. Proportional rounding may leave dust below it.
+    pub fn scheme_payout_total(&self, leaderboard: &Leaderboard) -> u64 {
+        match self.payout_scheme {
+            PayoutScheme::MerkleRoot => 0,
+            PayoutScheme::WinnerTakeAll => {
+                if leaderboard.entries.is_empty() { 0 } else { self.prize_pool }
+            }
+            PayoutScheme::Tiered => {
+                let awarded = self.awarded_tier_total(leaderboard);
+                self.awarded_tiers(leaderboard)
+                    .iter()
+                    .map(|bps| self.pool_share(*bps as u64, awarded))
+                    .sum()
+            }
+            PayoutScheme::EqualSplitPerfect if self.perfect_count > 0 => {
+                self.prize_pool / self.perfect_count as u64 * self.perfect_count as u64
+            }
+            PayoutScheme::EqualSplitPerfect | PayoutScheme::Proportional => self.pro_rata_total(),
+        }
+    }
+
+    /// What the creator and treasury may sweep from a settled contest: the
+    /// rake, prize pool the payouts left uncommitted (rounding dust), and
+    /// prizes still unclaimed when the claim window closed.
+    pub fn residual(&self) -> Result<u64> {
+        let gross = self.entry_fee
+            .checked_mul(self.total_entries as u64)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let rake = (gross + self.sponsored_amount)
+            .checked_sub(self.prize_pool)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let uncommitted = self.prize_pool
+            .checked_sub(self.payout_total)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let unclaimed = self.payout_total
+            .checked_sub(self.paid_so_far)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        Ok(rake + uncommitted + unclaimed)
+    }
+
+    /// Payouts are committed once a payout root is posted or, for computed
+    /// schemes, once every participant has been scored.
+    pub fn payouts_committed(&self) -> bool {
+        self.claim_deadline_slot != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contest(question_count: u8, option_count: u8) -> Contest {
+        Contest {
+            creator: Pubkey::default(),
+            contest_id: 0,
+            pool_mint: Pubkey::default(),
+            entry_fee: 0,
+            lock_slot: 0,
+            status: ContestStatus::Open,
+            total_entries: 0,
+            answer_key: 0,
+            payout_root: [0; 32],
+            winner_count: 0,
+            payout_total: 0,
+            paid_so_far: 0,
+            claim_deadline_slot: 0,
+            protocol_fee_bps: 0,
+            rake_bps: 0,
+            prize_pool: 0,
+            max_entries: 0,
+            min_entries: 0,
+            scored_count: 0,
+            payout_scheme: PayoutScheme::MerkleRoot,
+            tier_bps: [0; MAX_TIERS],
+            perfect_count: 0,
+            total_score: 0,
+            sponsored_amount: 0,
+            question_count,
+            min_attempts: 1,
+            max_attempts: question_count,
+            option_count,
+            question_weights: [1; MAX_QUESTIONS],
+            has_tiebreaker: false,
+            tiebreaker_result: 0,
+            void_mask: 0,
+            dispute_period_slots: 0,
+            answer_key_slot: 0,
+            answer_key_round: 0,
+            open_disputes: 0,
+            resolution_mode: ResolutionMode::Manual,
+            lock_mode: LockMode::Slot,
+            lock_unix_timestamp: 0,
+            metadata: ContestMetadata {
+                title: String::new(),
+                metadata_uri: String::new(),
+                questions_hash: [0; 32],
+            },
+            answer_commitment: [0; 32],
+            fees_swept: false,
+            feed_program: Pubkey::default(),
+            resolution_ready: true,
+            upheld_questions: 0,
+            contest_bump: 0,
+            vault_bump: 0,
+            vault_authority_bump: 0,
+        }
+    }
+
+    #[test]
+    fn answer_width_fits_the_largest_option() {
+        assert_eq!(answer_width(2), 1);
+        assert_eq!(answer_width(3), 2);
+        assert_eq!(answer_width(4), 2);
+        assert_eq!(answer_width(5), 3);
+        assert_eq!(answer_width(16), 4);
+    }
+
+    #[test]
+    fn three_options_reject_the_unused_fourth_value() {
+        let c = contest(4, 3);
+        // Options 2, 1, 0, 2 for questions 0..4, two bits each.
+        let packed = 0b10_00_01_10;
+        assert_eq!(c.answer_at(packed, 0), 2);
+        assert_eq!(c.answer_at(packed, 1), 1);
+        assert_eq!(c.answer_at(packed, 2), 0);
+        assert_eq!(c.answer_at(packed, 3), 2);
+        assert!(c.answers_valid(packed, 0b1111));
+
+        assert!(!c.answers_valid(0b11, 0b0001));
+    }
+
+    #[test]
+    fn four_options_use_every_value_of_the_field() {
+        let c = contest(4, 4);
+        assert!(c.answers_valid(0b11_10_01_00, 0b1111));
+        assert_eq!(c.answer_at(0b11_10_01_00, 3), 3);
+    }
+
+    #[test]
+    fn unattempted_fields_must_be_zero() {
+        let c = contest(4, 4);
+        // Question 1 answered but only question 0 attempted.
+        assert!(!c.answers_valid(0b01_00, 0b0001));
+        assert!(c.answers_valid(0b01_00, 0b0010));
+    }
+
+    #[test]
+    fn bits_past_the_last_question_are_rejected() {
+        let c = contest(4, 4);
+        assert!(!c.answers_valid(1 << 8, 0b1111));
+
+        let c = contest(3, 16);
+        assert!(c.answers_valid(0xfff, 0b111));
+        assert!(!c.answers_valid(1 << 12, 0b111));
+    }
+
+    #[test]
+    fn sixteen_options_fill_a_full_word() {
+        let c = contest(16, 16);
+        let packed = 0xf000_0000_0000_0001;
+        assert_eq!(c.answer_at(packed, 0), 1);
+        assert_eq!(c.answer_at(packed, 15), 15);
+        assert!(c.answers_valid(packed, 0xffff));
+        assert!(!c.answers_valid(packed, 0x7fff));
+    }
+
+    #[test]
+    fn without_voided_clears_only_voided_fields() {
+        let c = contest(4, 4);
+        assert_eq!(c.without_voided(0b11_10_01_11, 0b0101), 0b11_00_01_00);
+
+        let c = contest(16, 16);
+        assert_eq!(c.without_voided(u64::MAX, 1 << 15), u64::MAX >> 4);
+    }
 }
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// A participant's challenge to one posted answer key, identified by its
+/// `Contest::answer_key_round`. The bond is held as lamports on this account until the
+/// admin resolves it, which also closes the account.
+#[account]
+pub struct Dispute {
+    pub contest_id: u64,
+    pub disputer: Pubkey,
+    pub answer_key_round: u32,   // the answer key this challenges
+    pub disputed_questions: u32, // bit i set = question i is challenged
+    pub bond_lamports: u64,
+    pub resolved: bool,
+    pub upheld: bool,            // bond returned and answer key withdrawn
+    pub bump: u8,
+}
+
+impl Dispute {
+    pub const LEN: usize =
+        8  +            // Anchor account discriminator
+        8 + 32 +        // contest_id, disputer
+        4 + 4 +         // answer_key_round, disputed_questions
+        8 + 1 + 1 +     // bond_lamports, resolved, upheld
+        1;              // bump
+}
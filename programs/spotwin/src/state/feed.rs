@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_QUESTIONS;
+use crate::error::ErrorCode;
+
+/// Over/under binding for one question: the answer is 1 when the feed value
+/// is strictly greater than `threshold`, 0 otherwise.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct QuestionFeed {
+    pub feed: Pubkey,
+    pub threshold: i64,
+}
+
+impl QuestionFeed {
+    pub const LEN: usize = 32 + 8;
+}
+
+/// Feed accounts a `ResolutionMode::Feed` contest resolves from, one per
+/// question in question order.
+#[account]
+pub struct FeedBindings {
+    pub contest_id: u64,
+    pub questions: Vec<QuestionFeed>,
+    pub tiebreaker_feed: Pubkey, // default when the contest has no tiebreaker
+    pub bump: u8,
+}
+
+impl FeedBindings {
+    pub const LEN: usize =
+        8  +                                    // Anchor account discriminator
+        8  +                                    // contest_id
+        4 + QuestionFeed::LEN * MAX_QUESTIONS + // questions
+        32 + 1;                                 // tiebreaker_feed, bump
+}
+
+/// A published result read from a feed account. Feed accounts must be owned
+/// by the contest's `feed_program` and laid out as:
+///
+/// | bytes    | field                       |
+/// |----------|-----------------------------|
+/// | `0..8`   | discriminator (not checked) |
+/// | `8..16`  | `value: i64` LE             |
+/// | `16..24` | `publish_slot: u64` LE      |
+pub struct FeedResult {
+    pub value: i64,
+    pub publish_slot: u64,
+}
+
+impl FeedResult {
+    pub fn read(info: &AccountInfo, feed_program: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*info.owner, *feed_program, ErrorCode::InvalidFeed);
+        let data = info.try_borrow_data()?;
+        require!(data.len() >= 24, ErrorCode::InvalidFeed);
+
+        Ok(FeedResult {
+            value: i64::from_le_bytes(data[8..16].try_into().unwrap()),
+            publish_slot: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+        })
+    }
+}
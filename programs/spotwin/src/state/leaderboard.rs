@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LEADERBOARD_SIZE;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub score: u16,
+    pub tiebreak: u64, // lower ranks higher on equal score
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + 2 + 8;
+
+    /// Higher score first, then lower tiebreak, then player key so the order
+    /// never depends on which participant happened to be scored first.
+    fn outranks(&self, other: &LeaderboardEntry) -> bool {
+        (other.score, self.tiebreak, self.player) < (self.score, other.tiebreak, other.player)
+    }
+}
+
+/// Top `LEADERBOARD_SIZE` scored participants of a contest, best first.
+#[account]
+pub struct Leaderboard {
+    pub contest_id: u64,
+    pub entries: Vec<LeaderboardEntry>,
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const LEN: usize =
+        8  +                                        // Anchor account discriminator
+        8  +                                        // contest_id
+        4 + LeaderboardEntry::LEN * LEADERBOARD_SIZE + // entries
+        1;                                          // bump
+
+    pub fn rank_of(&self, player: &Pubkey) -> Option<usize> {
+        self.entries.iter().position(|e| e.player == *player)
+    }
+
+    /// Inserts `entry` at its rank, dropping whoever falls off the bottom.
+    pub fn insert(&mut self, entry: LeaderboardEntry) {
+        let pos = self
+            .entries
+            .iter()
+            .position(|e| entry.outranks(e))
+            .unwrap_or(self.entries.len());
+        if pos < LEADERBOARD_SIZE {
+            self.entries.insert(pos, entry);
+            self.entries.truncate(LEADERBOARD_SIZE);
+        }
+    }
+}
@@ -1,3 +1,9 @@
 pub mod contest;
 pub mod participant;
-pub mod stake;
\ No newline at end of file
+pub mod stake;
+pub mod config;
+pub mod leaderboard;
+pub mod sponsorship;
+pub mod dispute;
+pub mod feed;
+pub mod resolution_votes;
\ No newline at end of file
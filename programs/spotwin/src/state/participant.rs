@@ -4,14 +4,22 @@ use anchor_lang::prelude::*;
 #[account]
 pub struct Participant {
  pub player: Pubkey,
- pub attempt_mask: u16,
- pub answer_bits:  u16,
+ pub attempt_mask: u32, // bit i set = question i attempted
+ pub answer_bits:  u64, // packed answers, see Contest::answer_width
+ pub paid_amount:  u64, // prize paid out to this player, 0 until paid
+ pub refunded:     bool, // entry fee returned after cancellation
+ pub score:        u16,  // weighted points, valid once `scored`
+ pub scored:       bool,
+ pub joined_slot:  u64,  // leaderboard tiebreak, earlier entry ranks higher
+ pub tiebreaker_prediction: i64, // only used when the contest has a tiebreaker
 }
 
 impl Participant {
     pub const LEN: usize =
         8  +            // Anchor account discriminator
-        32 + 2 + 2 +     // player, attempt_mask, answer_bits
+        32 + 4 + 8 +     // player, attempt_mask, answer_bits
+        8 + 1 +          // paid_amount, refunded
+        2 + 1 +          // score, scored
+        8 + 8 +          // joined_slot, tiebreaker_prediction
         1;             // vault_bump
-    
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RESOLVERS;
+
+/// One resolver's proposed outcome for the current round.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ResolutionSubmission {
+    pub resolver: Pubkey,
+    pub answer_key: u64,
+    pub void_mask: u32,
+    pub tiebreaker_result: Option<i64>,
+}
+
+impl ResolutionSubmission {
+    pub const LEN: usize = 32 + 8 + 4 + (1 + 8);
+
+    pub fn matches(&self, other: &ResolutionSubmission) -> bool {
+        self.answer_key == other.answer_key
+            && self.void_mask == other.void_mask
+            && self.tiebreaker_result == other.tiebreaker_result
+    }
+}
+
+/// Resolver set of a `ResolutionMode::Quorum` contest and the submissions
+/// of the current round. A round ends when `threshold` submissions match;
+/// they are then cleared so an upheld dispute starts a fresh round.
+#[account]
+pub struct ResolutionVotes {
+    pub contest_id: u64,
+    pub resolvers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub submissions: Vec<ResolutionSubmission>,
+    pub disagreements: u32, // submissions that conflicted with an earlier one
+    pub bump: u8,
+}
+
+impl ResolutionVotes {
+    pub const LEN: usize =
+        8  +                                            // Anchor account discriminator
+        8  +                                            // contest_id
+        4 + 32 * MAX_RESOLVERS + 1 +                    // resolvers, threshold
+        4 + ResolutionSubmission::LEN * MAX_RESOLVERS + // submissions
+        4 + 1;                                          // disagreements, bump
+
+    pub fn is_resolver(&self, key: &Pubkey) -> bool {
+        self.resolvers.contains(key)
+    }
+}
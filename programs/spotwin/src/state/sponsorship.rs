@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// One sponsor's contributions to a contest's prize pool.
+#[account]
+pub struct Sponsorship {
+    pub contest_id: u64,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub refunded: bool, // returned after cancellation
+    pub bump: u8,
+}
+
+impl Sponsorship {
+    pub const LEN: usize =
+        8  +            // Anchor account discriminator
+        8 + 32 + 8 +    // contest_id, sponsor, amount
+        1 + 1;          // refunded, bump
+}
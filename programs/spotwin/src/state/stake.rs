@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
 
+use crate::state::contest::LockMode;
+
 #[account]
 pub struct StakeAccount  {
     pub owner: Pubkey,
     pub amount: u64,
     pub start_slot: u64,
+    pub start_unix_timestamp: i64,
+    pub lock_mode: LockMode,  // snapshot of the config at the latest stake
+    pub lock_period: u64,
 }
 
 impl StakeAccount {
@@ -12,5 +17,11 @@ impl StakeAccount {
         8   + // discriminator
         32  + // owner
         8   + // amount
-        8 + 1;    // start_slot
-}
\ No newline at end of file
+        8 + 1 +   // start_slot
+        8 +       // start_unix_timestamp
+        1 + 8;    // lock_mode, lock_period
+
+    /// Size of accounts created before the unix-timestamp and lock snapshot
+    /// fields; `migrate_stake` grows them to `LEN`.
+    pub const LEGACY_LEN: usize = 8 + 32 + 8 + 8 + 1;
+}
@@ -0,0 +1,70 @@
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    cancelContest,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    program,
+    refundEntry,
+    setupConfig,
+    tokenBalance,
+} from "./utils";
+
+describe('cancellation', () => {
+    let contest: Contest;
+    let alice: Player;
+    let bob: Player;
+    let stranger: Player;
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint);
+        contest = await createContest(mint);
+        [alice, bob, stranger] = await Promise.all([newPlayer(mint), newPlayer(mint), newPlayer(mint)]);
+        await join(contest, alice);
+        await join(contest, bob);
+        await lockContest(contest);
+    });
+
+    it('rejects refunds before the contest is cancelled', async () => {
+        await expectError(refundEntry(contest, alice), "ContestNotCancelled");
+    });
+
+    it('rejects cancellation by anyone but the creator or admin', async () => {
+        await expectError(cancelContest(contest, stranger.kp), "Unauthorized");
+    });
+
+    it('lets the creator cancel a locked contest', async () => {
+        await cancelContest(contest);
+        assert.ok((await contest.fetch()).status.hasOwnProperty('cancelled'));
+    });
+
+    it('refunds the entry fee exactly once', async () => {
+        const entryFee = (await contest.fetch()).entryFee;
+        const before = await tokenBalance(alice.ata);
+
+        await refundEntry(contest, alice);
+        assert.equal((await tokenBalance(alice.ata)).sub(before).toString(), entryFee.toString());
+        const participant = await program.account.participant.fetch(contest.participant(alice.kp.publicKey));
+        assert.ok(participant.refunded);
+
+        await expectError(refundEntry(contest, alice), "AlreadyRefunded");
+        assert.equal((await tokenBalance(alice.ata)).sub(before).toString(), entryFee.toString());
+    });
+
+    it('refunds every other participant from the same vault', async () => {
+        const before = await tokenBalance(bob.ata);
+        await refundEntry(contest, bob);
+        assert.equal((await tokenBalance(bob.ata)).sub(before).toString(), "1000000");
+        assert.equal((await tokenBalance(contest.vault)).toString(), "0");
+    });
+
+    it('rejects cancelling a contest twice', async () => {
+        await expectError(cancelContest(contest), "ContestNotCancellable");
+    });
+});
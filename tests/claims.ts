@@ -0,0 +1,123 @@
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import {
+    Contest,
+    Player,
+    claimPrize,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    payoutLeaf,
+    payoutTree,
+    postAnswerKey,
+    payer,
+    postPayoutRoot,
+    program,
+    provider,
+    settleContest,
+    setupConfig,
+    sweepFees,
+    tokenBalance,
+} from "./utils";
+
+describe('claims', () => {
+    let contest: Contest;
+    let alice: Player;
+    let bob: Player;
+    let carol: Player;
+    let proofs: number[][][];
+    // 3 entries of 1_000_000 minus 10% rake.
+    const prizePool = new BN(2_700_000);
+    const aliceAmount = new BN(1_500_000);
+    const bobAmount = prizePool.sub(aliceAmount);
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint);
+        contest = await createContest(mint);
+        [alice, bob, carol] = await Promise.all([newPlayer(mint), newPlayer(mint), newPlayer(mint)]);
+        for (const player of [alice, bob, carol]) {
+            await join(contest, player);
+        }
+        await lockContest(contest);
+        await postAnswerKey(contest, 0b1011);
+
+        const tree = payoutTree([
+            payoutLeaf(contest.id, alice.kp.publicKey, aliceAmount),
+            payoutLeaf(contest.id, bob.kp.publicKey, bobAmount),
+        ]);
+        proofs = tree.proofs;
+        await postPayoutRoot(contest, tree.root, 2, prizePool);
+    });
+
+    it('rejects a payout total below the prize pool', async () => {
+        const fresh = await createContest(contest.mint);
+        await join(fresh, carol);
+        await lockContest(fresh);
+        await postAnswerKey(fresh, 0b1);
+        const leaf = payoutLeaf(fresh.id, carol.kp.publicKey, new BN(1));
+        await expectError(postPayoutRoot(fresh, leaf, 1, new BN(1)), "PayoutTotalMismatch");
+    });
+
+    it('accepts a payout root when every answer is 0', async () => {
+        const fresh = await createContest(contest.mint);
+        await join(fresh, carol);
+        await lockContest(fresh);
+        await postAnswerKey(fresh, 0);
+        const { prizePool: freshPool } = await fresh.fetch();
+        await postPayoutRoot(fresh, payoutLeaf(fresh.id, carol.kp.publicKey, freshPool), 1, freshPool);
+        assert.equal((await fresh.fetch()).winnerCount, 1);
+    });
+
+    it('rejects a second payout root', async () => {
+        const other = payoutLeaf(contest.id, carol.kp.publicKey, prizePool);
+        await expectError(postPayoutRoot(contest, other, 1, prizePool), "PayoutRootAlreadySet");
+    });
+
+    it('rejects a proof for a different amount', async () => {
+        await expectError(claimPrize(contest, alice, aliceAmount.addn(1), proofs[0]), "InvalidProof");
+    });
+
+    it('rejects a player outside the payout tree', async () => {
+        await expectError(claimPrize(contest, carol, aliceAmount, proofs[0]), "InvalidProof");
+    });
+
+    it('pays a winner presenting a valid proof', async () => {
+        const before = await tokenBalance(alice.ata);
+        await claimPrize(contest, alice, aliceAmount, proofs[0]);
+
+        assert.equal((await tokenBalance(alice.ata)).sub(before).toString(), aliceAmount.toString());
+        const participant = await program.account.participant.fetch(contest.participant(alice.kp.publicKey));
+        assert.equal(participant.paidAmount.toString(), aliceAmount.toString());
+        assert.equal((await contest.fetch()).paidSoFar.toString(), aliceAmount.toString());
+    });
+
+    it('rejects a second claim by the same winner', async () => {
+        await expectError(claimPrize(contest, alice, aliceAmount, proofs[0]), "AlreadyPaid");
+    });
+
+    it('pays the rest of the committed total', async () => {
+        await claimPrize(contest, bob, bobAmount, proofs[1]);
+        assert.equal((await contest.fetch()).paidSoFar.toString(), prizePool.toString());
+    });
+
+    it('sweeps only the rake, leaving stray deposits in the vault', async () => {
+        await settleContest(contest);
+        const payerAta = (await getOrCreateAssociatedTokenAccount(
+            provider.connection, payer.payer, contest.mint, payer.publicKey
+        )).address;
+        // Tokens sent straight to the vault are not part of any contest balance.
+        await mintTo(provider.connection, payer.payer, contest.mint, contest.vault, payer.payer, 42);
+
+        const before = await tokenBalance(payerAta);
+        await sweepFees(contest, payerAta);
+        assert.equal((await tokenBalance(payerAta)).sub(before).toString(), "300000");
+        assert.equal((await tokenBalance(contest.vault)).toString(), "42");
+
+        await expectError(sweepFees(contest, payerAta), "NothingToSweep");
+    });
+});
@@ -0,0 +1,93 @@
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { randomBytes } from "crypto";
+import {
+    Contest,
+    Player,
+    answerCommitment,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    openDispute,
+    postAnswerKey,
+    resolveDispute,
+    setupConfig,
+} from "./utils";
+
+describe('answer commitment', () => {
+    const answerKey = 0b0110;
+    const salt = randomBytes(32);
+    let contest: Contest;
+    let alice: Player;
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint);
+        contest = await createContest(mint, {
+            disputePeriodSlots: new BN(1_000),
+            answerCommitment: answerCommitment(new BN(answerKey), salt),
+        });
+        alice = await newPlayer(mint);
+        await join(contest, alice);
+        await lockContest(contest);
+    });
+
+    it('rejects a key or salt that does not open the commitment', async () => {
+        await expectError(postAnswerKey(contest, 0b0111, 0, null, Array.from(salt)), "AnswerCommitmentMismatch");
+        await expectError(postAnswerKey(contest, answerKey, 0, null, Array.from(randomBytes(32))), "AnswerCommitmentMismatch");
+    });
+
+    it('rejects voiding a question no upheld dispute challenged', async () => {
+        await expectError(postAnswerKey(contest, answerKey, 0b0010, null, Array.from(salt)), "QuestionNotVoidable");
+    });
+
+    it('posts the committed key on a matching reveal', async () => {
+        await postAnswerKey(contest, answerKey, 0, null, Array.from(salt));
+        const c = await contest.fetch();
+        assert.ok("answerKeyPosted" in c.status);
+        assert.equal(c.answerKey.toNumber(), answerKey);
+    });
+
+    it('voids a challenged question on the reveal after an upheld dispute', async () => {
+        const dispute = await openDispute(contest, alice, 0b0010);
+        await resolveDispute(contest, alice, dispute, true);
+        assert.equal((await contest.fetch()).upheldQuestions, 0b0010);
+
+        // The reveal still opens the commitment with question 1 answered; it
+        // is cleared from the posted key along with the void.
+        await postAnswerKey(contest, answerKey, 0b0010, null, Array.from(salt));
+        const c = await contest.fetch();
+        assert.ok("answerKeyPosted" in c.status);
+        assert.equal(c.answerKey.toNumber(), 0b0100);
+        assert.equal(c.voidMask, 0b0010);
+    });
+
+    describe('with a tiebreaker', () => {
+        const tiebreaker = new BN(-3);
+        let tbContest: Contest;
+
+        before(async () => {
+            tbContest = await createContest(contest.mint, {
+                hasTiebreaker: true,
+                answerCommitment: answerCommitment(new BN(answerKey), salt, tiebreaker),
+            });
+            await join(tbContest, alice);
+            await lockContest(tbContest);
+        });
+
+        it('rejects a tiebreaker result other than the committed one', async () => {
+            await expectError(
+                postAnswerKey(tbContest, answerKey, 0, new BN(4), Array.from(salt)),
+                "AnswerCommitmentMismatch",
+            );
+        });
+
+        it('posts the committed tiebreaker result', async () => {
+            await postAnswerKey(tbContest, answerKey, 0, tiebreaker, Array.from(salt));
+            assert.equal((await tbContest.fetch()).tiebreakerResult.toNumber(), -3);
+        });
+    });
+});
@@ -0,0 +1,77 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    createContest,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    openDispute,
+    postAnswerKey,
+    provider,
+    resolveDispute,
+    setupConfig,
+} from "./utils";
+
+const BOND = 10_000_000;
+
+describe('disputes', () => {
+    let contest: Contest;
+    let alice: Player;
+    let bob: Player;
+    let aliceDispute: anchor.web3.PublicKey;
+    let bobDispute: anchor.web3.PublicKey;
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint, { disputeBondLamports: new BN(BOND) });
+        contest = await createContest(mint, { disputePeriodSlots: new BN(1_000) });
+        [alice, bob] = await Promise.all([newPlayer(mint), newPlayer(mint)]);
+        await join(contest, alice);
+        await join(contest, bob);
+        await lockContest(contest);
+        await postAnswerKey(contest, 0b0101);
+        assert.equal((await contest.fetch()).answerKeyRound, 1);
+
+        aliceDispute = await openDispute(contest, alice, 0b0001);
+        bobDispute = await openDispute(contest, bob, 0b0010);
+        assert.equal((await contest.fetch()).openDisputes, 2);
+    });
+
+    it('withdraws the answer key and closes the dispute when upheld', async () => {
+        const held = await provider.connection.getBalance(aliceDispute);
+        assert.equal(await resolveDispute(contest, alice, aliceDispute, true), held);
+        assert.isNull(await provider.connection.getAccountInfo(aliceDispute));
+
+        const c = await contest.fetch();
+        assert.ok("locked" in c.status);
+        // bob's dispute was against the withdrawn key and no longer counts.
+        assert.equal(c.openDisputes, 0);
+    });
+
+    it('returns the bond on a stale dispute even when rejected', async () => {
+        const held = await provider.connection.getBalance(bobDispute);
+        assert.equal(await resolveDispute(contest, bob, bobDispute, false), held);
+        assert.isNull(await provider.connection.getAccountInfo(bobDispute));
+        assert.ok("locked" in (await contest.fetch()).status);
+    });
+
+    it('lets a player dispute the corrected key and slashes a rejected bond', async () => {
+        await postAnswerKey(contest, 0b0110);
+        // A new round even if the key lands in the same slot as the withdrawal.
+        assert.equal((await contest.fetch()).answerKeyRound, 2);
+        const dispute = await openDispute(contest, alice, 0b0001);
+        assert.notEqual(dispute.toBase58(), aliceDispute.toBase58());
+
+        const held = await provider.connection.getBalance(dispute);
+        assert.equal(await resolveDispute(contest, alice, dispute, false), held - BOND);
+        assert.isNull(await provider.connection.getAccountInfo(dispute));
+
+        const c = await contest.fetch();
+        assert.ok("answerKeyPosted" in c.status);
+        assert.equal(c.openDisputes, 0);
+    });
+});
@@ -0,0 +1,66 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    refundEntry,
+    setupConfig,
+    tokenBalance,
+} from "./utils";
+
+describe('entry limits', () => {
+    let mint: anchor.web3.PublicKey;
+    let alice: Player;
+    let bob: Player;
+    let carol: Player;
+
+    before(async () => {
+        mint = await newMint();
+        await setupConfig(mint);
+        [alice, bob, carol] = await Promise.all([newPlayer(mint), newPlayer(mint), newPlayer(mint)]);
+    });
+
+    it('rejects a minimum above the cap', async () => {
+        await expectError(createContest(mint, { minEntries: 3, maxEntries: 2 }), "InvalidEntryLimits");
+    });
+
+    it('rejects joins past max_entries', async () => {
+        const contest = await createContest(mint, { maxEntries: 2 });
+        await join(contest, alice);
+        await join(contest, bob);
+        await expectError(join(contest, carol), "ContestFull");
+        assert.equal((await contest.fetch()).totalEntries, 2);
+    });
+
+    describe('below min_entries', () => {
+        let contest: Contest;
+
+        before(async () => {
+            contest = await createContest(mint, { minEntries: 3 });
+            await join(contest, alice);
+            await join(contest, bob);
+        });
+
+        it('cancels the contest instead of locking it', async () => {
+            await lockContest(contest);
+            const c = await contest.fetch();
+            assert.ok("cancelled" in c.status);
+            assert.equal(c.prizePool.toNumber(), 0);
+        });
+
+        it('refunds every entry', async () => {
+            for (const player of [alice, bob]) {
+                const before = await tokenBalance(player.ata);
+                await refundEntry(contest, player);
+                assert.equal((await tokenBalance(player.ata)).sub(before).toNumber(), 1_000_000);
+            }
+            assert.equal((await tokenBalance(contest.vault)).toNumber(), 0);
+        });
+    });
+});
@@ -0,0 +1,112 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    openDispute,
+    payer,
+    program,
+    provider,
+    resolveDispute,
+    setupConfig,
+    waitForSlot,
+} from "./utils";
+
+const { PublicKey } = anchor.web3;
+
+// Preloaded from tests/fixtures (see Anchor.toml): feed accounts owned by a
+// stand-in feed program, published at u64::MAX so they always post-date the lock.
+const MOCK_FEED_PROGRAM = new PublicKey("6pbcUnGRUnQYXiAcygb3QYjH4Wf864p6j6cRnfmfhKsM");
+const FEED_OVER = new PublicKey("84wGYM5exGmghRi3xdQ5sNqzc3NrTQBBojh26yeopdnh"); // value 100
+const FEED_UNDER = new PublicKey("3t12n6C59R6qepCg1JVRXDPGyNqeRhzP61cD3x7C7B1K"); // value -5
+
+function bindQuestionFeeds(contest: Contest) {
+    return program.methods
+        .bindQuestionFeeds(
+            contest.id,
+            [
+                { feed: FEED_OVER, threshold: new BN(0) },
+                { feed: FEED_UNDER, threshold: new BN(0) },
+            ],
+            null,
+        )
+        .accountsStrict({
+            creator: payer.publicKey,
+            contest: contest.pda,
+            feedBindings: contest.derive("feeds"),
+            systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+}
+
+function resolveFromFeed(contest: Contest) {
+    return program.methods
+        .resolveFromFeed(contest.id)
+        .accountsStrict({ contest: contest.pda, feedBindings: contest.derive("feeds") })
+        .remainingAccounts([FEED_OVER, FEED_UNDER].map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+        .rpc();
+}
+
+describe('feed resolution', () => {
+    let mint: anchor.web3.PublicKey;
+    let contest: Contest;
+    let alice: Player;
+    let lockSlot: number;
+
+    before(async () => {
+        mint = await newMint();
+        await setupConfig(mint, { feedProgram: MOCK_FEED_PROGRAM });
+        lockSlot = (await provider.connection.getSlot()) + 30;
+        contest = await createContest(mint, {
+            questionCount: 2,
+            maxAttempts: 2,
+            resolutionMode: { feed: {} },
+            lockSlot: new BN(lockSlot),
+            disputePeriodSlots: new BN(1_000),
+        });
+        alice = await newPlayer(mint);
+    });
+
+    it('rejects joins until the feeds are bound', async () => {
+        await expectError(join(contest, alice), "ResolutionNotConfigured");
+        await bindQuestionFeeds(contest);
+        await join(contest, alice);
+        assert.equal((await contest.fetch()).totalEntries, 1);
+    });
+
+    it('resolves from the feed program snapshotted at creation', async () => {
+        // Later config changes must not redirect an existing contest's feeds.
+        await setupConfig(mint);
+        await waitForSlot(lockSlot);
+        await lockContest(contest);
+        await resolveFromFeed(contest);
+
+        const c = await contest.fetch();
+        assert.ok("answerKeyPosted" in c.status);
+        assert.equal(c.answerKey.toNumber(), 0b01);
+    });
+
+    it('voids upheld questions when re-resolving from the same feeds', async () => {
+        const dispute = await openDispute(contest, alice, 0b01);
+        await resolveDispute(contest, alice, dispute, true);
+        assert.ok("locked" in (await contest.fetch()).status);
+
+        await resolveFromFeed(contest);
+        const c = await contest.fetch();
+        assert.ok("answerKeyPosted" in c.status);
+        assert.equal(c.voidMask, 0b01);
+        assert.equal(c.answerKey.toNumber(), 0);
+        assert.equal(c.answerKeyRound, 2);
+    });
+
+    it('rejects feed contests when no feed program is configured', async () => {
+        await expectError(createContest(mint, { resolutionMode: { feed: {} } }), "InvalidFeed");
+    });
+});
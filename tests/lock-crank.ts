@@ -0,0 +1,51 @@
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    crankLock,
+    createContest,
+    expectError,
+    join,
+    newMint,
+    newPlayer,
+    provider,
+    setupConfig,
+    waitForSlot,
+} from "./utils";
+
+describe('lock crank', () => {
+    let contest: Contest;
+    let alice: Player;
+    let bob: Player;
+    let lockSlot: number;
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint);
+        lockSlot = (await provider.connection.getSlot()) + 20;
+        contest = await createContest(mint, { lockSlot: new BN(lockSlot) });
+        [alice, bob] = await Promise.all([newPlayer(mint), newPlayer(mint)]);
+        await join(contest, alice);
+    });
+
+    it('rejects the crank before the lock slot', async () => {
+        await expectError(crankLock(contest), "LockSlotNotReached");
+    });
+
+    it('rejects joins once the lock slot has passed', async () => {
+        await waitForSlot(lockSlot);
+        await expectError(join(contest, bob), "ContestClosed");
+    });
+
+    it('lets anyone lock the contest after the lock slot', async () => {
+        await crankLock(contest);
+
+        const c = await contest.fetch();
+        assert.ok("locked" in c.status);
+        assert.equal(c.totalEntries, 1);
+        assert.equal(c.prizePool.toNumber(), 900_000);
+
+        await expectError(crankLock(contest), "ContestClosed");
+    });
+});
@@ -0,0 +1,126 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    chainTime,
+    crankLock,
+    createContest,
+    ensureStakeVault,
+    expectError,
+    join,
+    newMint,
+    newPlayer,
+    program,
+    provider,
+    setupConfig,
+    stakePda,
+    stakeTokens,
+    submitAnswers,
+    unstakeTokens,
+    waitForChainTime,
+} from "./utils";
+
+describe('unix-timestamp lock mode', () => {
+    let mint: anchor.web3.PublicKey;
+    let alice: Player;
+    let bob: Player;
+
+    before(async () => {
+        mint = await newMint();
+        await setupConfig(mint);
+        [alice, bob] = await Promise.all([newPlayer(mint), newPlayer(mint)]);
+    });
+
+    describe('deadline validation', () => {
+        it('rejects a unix-timestamp contest without a timestamp', async () => {
+            await expectError(
+                createContest(mint, { lockMode: { unixTimestamp: {} }, lockUnixTimestamp: new BN(0) }),
+                "InvalidLockDeadline",
+            );
+        });
+
+        it('rejects a unix-timestamp contest that also sets a lock slot', async () => {
+            await expectError(
+                createContest(mint, {
+                    lockMode: { unixTimestamp: {} },
+                    lockSlot: new BN(1),
+                    lockUnixTimestamp: new BN((await chainTime()) + 60),
+                }),
+                "InvalidLockDeadline",
+            );
+        });
+
+        it('rejects a slot contest that also sets a timestamp', async () => {
+            await expectError(
+                createContest(mint, { lockUnixTimestamp: new BN((await chainTime()) + 60) }),
+                "InvalidLockDeadline",
+            );
+        });
+    });
+
+    describe('contest', () => {
+        let contest: Contest;
+        let deadline: number;
+
+        before(async () => {
+            deadline = (await chainTime()) + 8;
+            contest = await createContest(mint, {
+                lockMode: { unixTimestamp: {} },
+                lockUnixTimestamp: new BN(deadline),
+            });
+            await join(contest, alice);
+            await submitAnswers(contest, alice, 0b0001, 0b0001);
+        });
+
+        it('rejects the crank before the deadline', async () => {
+            await expectError(crankLock(contest), "LockSlotNotReached");
+        });
+
+        it('rejects joins and answer changes after the deadline', async () => {
+            await waitForChainTime(deadline);
+            await expectError(join(contest, bob), "ContestClosed");
+            await expectError(submitAnswers(contest, alice, 0b0011, 0b0011), "ContestClosed");
+        });
+
+        it('locks through the crank and records the lock slot', async () => {
+            const before = await provider.connection.getSlot();
+            await crankLock(contest);
+
+            const c = await contest.fetch();
+            assert.ok("locked" in c.status);
+            assert.isAtLeast(c.lockSlot.toNumber(), before);
+            assert.isAtMost(c.lockSlot.toNumber(), await provider.connection.getSlot());
+        });
+    });
+
+    describe('staking', () => {
+        const LOCK_SECONDS = 4;
+        let staker: Player;
+
+        before(async () => {
+            const stakeMint = await ensureStakeVault(mint);
+            await setupConfig(stakeMint, {
+                stakeLockMode: { unixTimestamp: {} },
+                stakeLockPeriod: new BN(LOCK_SECONDS),
+            });
+            staker = await newPlayer(stakeMint);
+        });
+
+        after(async () => {
+            await setupConfig(mint);
+        });
+
+        it('keeps a stake locked for the period in seconds', async () => {
+            await stakeTokens(staker, 100_000);
+            const stake = await program.account.stakeAccount.fetch(stakePda(staker.kp.publicKey));
+            assert.ok("unixTimestamp" in stake.lockMode);
+            assert.equal(stake.lockPeriod.toNumber(), LOCK_SECONDS);
+
+            await expectError(unstakeTokens(staker, 100_000), "StakeLocked");
+            await waitForChainTime(stake.startUnixTimestamp.toNumber() + LOCK_SECONDS);
+            await unstakeTokens(staker, 100_000);
+        });
+    });
+});
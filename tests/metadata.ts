@@ -0,0 +1,62 @@
+import { assert } from "chai";
+import { createHash } from "crypto";
+import {
+    Contest,
+    Player,
+    createContest,
+    expectError,
+    join,
+    newMint,
+    newPlayer,
+    payer,
+    program,
+    setupConfig,
+} from "./utils";
+
+function metadata(title: string, metadataUri = "https://example.com/contest.json", questions = "q1|q2|q3|q4") {
+    return {
+        title,
+        metadataUri,
+        questionsHash: Array.from(createHash("sha256").update(questions).digest()),
+    };
+}
+
+function updateMetadata(contest: Contest, meta: ReturnType<typeof metadata>) {
+    return program.methods
+        .updateMetadata(contest.id, meta)
+        .accountsStrict({ creator: payer.publicKey, contest: contest.pda })
+        .rpc();
+}
+
+describe('contest metadata', () => {
+    let contest: Contest;
+    let alice: Player;
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint);
+        contest = await createContest(mint, { metadata: metadata("Matchday 1") });
+        alice = await newPlayer(mint);
+    });
+
+    it('rejects a title or URI over the limit', async () => {
+        await expectError(createContest(contest.mint, { metadata: metadata("t".repeat(65)) }), "MetadataTooLong");
+        await expectError(updateMetadata(contest, metadata("Matchday 1", "u".repeat(201))), "MetadataTooLong");
+    });
+
+    it('lets the creator replace the metadata before anyone joins', async () => {
+        const updated = metadata("Matchday 1 (rescheduled)", undefined, "q1|q2|q3|q5");
+        await updateMetadata(contest, updated);
+
+        const { metadata: stored } = await contest.fetch();
+        assert.equal(stored.title, updated.title);
+        assert.equal(stored.metadataUri, updated.metadataUri);
+        assert.deepEqual(stored.questionsHash, updated.questionsHash);
+    });
+
+    it('freezes the metadata once the first player joins', async () => {
+        await join(contest, alice);
+        await expectError(updateMetadata(contest, metadata("Swapped")), "MetadataFrozen");
+        assert.equal((await contest.fetch()).metadata.title, "Matchday 1 (rescheduled)");
+    });
+});
@@ -0,0 +1,95 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    payer,
+    program,
+    provider,
+    setupConfig,
+} from "./utils";
+
+const { Keypair } = anchor.web3;
+
+function configureResolvers(contest: Contest, resolvers: anchor.web3.PublicKey[], threshold: number) {
+    return program.methods
+        .configureResolvers(contest.id, resolvers, threshold)
+        .accountsStrict({
+            creator: payer.publicKey,
+            contest: contest.pda,
+            resolutionVotes: contest.derive("votes"),
+            systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+}
+
+// Submits a vote and returns the names of the events it emitted.
+async function submitResolution(contest: Contest, resolver: anchor.web3.Keypair, answerKey: number) {
+    const sig = await program.methods
+        .submitResolution(contest.id, new BN(answerKey), 0, null)
+        .accountsStrict({
+            resolver: resolver.publicKey,
+            contest: contest.pda,
+            resolutionVotes: contest.derive("votes"),
+        })
+        .signers([resolver])
+        .rpc({ commitment: "confirmed" });
+    const tx = await provider.connection.getTransaction(sig, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(tx.meta.logMessages)].map((event) => event.name);
+}
+
+describe('quorum resolution', () => {
+    const resolvers = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    let contest: Contest;
+    let alice: Player;
+
+    before(async () => {
+        const mint = await newMint();
+        await setupConfig(mint);
+        contest = await createContest(mint, { questionCount: 2, maxAttempts: 2, resolutionMode: { quorum: {} } });
+        alice = await newPlayer(mint);
+    });
+
+    it('rejects joins until the resolver set is configured', async () => {
+        await expectError(join(contest, alice), "ResolutionNotConfigured");
+        await configureResolvers(contest, resolvers.map((r) => r.publicKey), 2);
+        await join(contest, alice);
+        await lockContest(contest);
+    });
+
+    it('rejects votes from outside the resolver set', async () => {
+        await expectError(submitResolution(contest, Keypair.generate(), 0b01), "NotAResolver");
+    });
+
+    it('records a disagreement without posting a key', async () => {
+        assert.notInclude(await submitResolution(contest, resolvers[0], 0b01), "resolutionDisagreement");
+        assert.include(await submitResolution(contest, resolvers[1], 0b10), "resolutionDisagreement");
+
+        const votes = await program.account.resolutionVotes.fetch(contest.derive("votes"));
+        assert.equal(votes.disagreements, 1);
+        assert.equal(votes.submissions.length, 2);
+        assert.ok("locked" in (await contest.fetch()).status);
+    });
+
+    it('posts the answer key once the threshold agrees', async () => {
+        const events = await submitResolution(contest, resolvers[2], 0b01);
+        assert.include(events, "answerKeyPosted");
+
+        const c = await contest.fetch();
+        assert.ok("answerKeyPosted" in c.status);
+        assert.equal(c.answerKey.toNumber(), 0b01);
+        const votes = await program.account.resolutionVotes.fetch(contest.derive("votes"));
+        assert.equal(votes.submissions.length, 0);
+    });
+});
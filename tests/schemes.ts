@@ -0,0 +1,131 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    claimSchemePrize,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    postAnswerKey,
+    program,
+    score,
+    settleContest,
+    setupConfig,
+    submitAnswers,
+    tokenBalance,
+} from "./utils";
+
+// q0 = 1, q1 = 0, q2 = 1, q3 = 0
+const ANSWER_KEY = 0b0101;
+// 2 entries of 1_000_000 minus 10% rake.
+const PRIZE_POOL = new BN(1_800_000);
+
+interface Entry {
+    bits: number;
+    mask: number;
+}
+
+// Runs a two-player contest through scoring; returns the players in entry order.
+async function scoredContest(
+    mint: anchor.web3.PublicKey,
+    overrides: Record<string, unknown>,
+    entries: Entry[],
+): Promise<[Contest, Player[]]> {
+    const contest = await createContest(mint, overrides);
+    const players = await Promise.all(entries.map(() => newPlayer(mint)));
+    for (const [i, player] of players.entries()) {
+        await join(contest, player);
+        await submitAnswers(contest, player, entries[i].bits, entries[i].mask);
+    }
+    await lockContest(contest);
+    await postAnswerKey(contest, ANSWER_KEY);
+    for (const player of players) {
+        await score(contest, player);
+    }
+    return [contest, players];
+}
+
+async function claimedAmount(contest: Contest, player: Player): Promise<BN> {
+    const before = await tokenBalance(player.ata);
+    await claimSchemePrize(contest, player);
+    return (await tokenBalance(player.ata)).sub(before);
+}
+
+describe('payout schemes', () => {
+    let mint: anchor.web3.PublicKey;
+
+    before(async () => {
+        mint = await newMint();
+        await setupConfig(mint);
+    });
+
+    it('winner-take-all pays rank 0 the whole pool', async () => {
+        const [contest, [alice, bob]] = await scoredContest(mint, { payoutScheme: { winnerTakeAll: {} } }, [
+            { bits: 0b0101, mask: 0b1111 }, // 4 correct
+            { bits: 0b1010, mask: 0b1111 }, // 0 correct
+        ]);
+
+        assert.equal((await contest.fetch()).payoutTotal.toString(), PRIZE_POOL.toString());
+        assert.equal((await claimedAmount(contest, alice)).toString(), PRIZE_POOL.toString());
+        await expectError(claimSchemePrize(contest, alice), "AlreadyPaid");
+        await expectError(claimSchemePrize(contest, bob), "NoPrize");
+        await settleContest(contest);
+    });
+
+    it('equal split without a perfect score falls back to pro rata', async () => {
+        const [contest, [alice, bob]] = await scoredContest(mint, { payoutScheme: { equalSplitPerfect: {} } }, [
+            { bits: 0b0011, mask: 0b0011 }, // q0 right, q1 wrong: 1 point
+            { bits: 0b0111, mask: 0b0111 }, // q0, q2 right, q1 wrong: 2 points
+        ]);
+
+        const state = await contest.fetch();
+        assert.equal(state.perfectCount, 0);
+        assert.equal(state.payoutTotal.toString(), PRIZE_POOL.toString());
+        assert.equal((await claimedAmount(contest, alice)).toString(), "600000");
+        assert.equal((await claimedAmount(contest, bob)).toString(), "1200000");
+    });
+
+    it('rejects a tier table that does not cover the whole pool', async () => {
+        await expectError(
+            createContest(mint, { payoutScheme: { tiered: {} }, tierBps: [5_000, 3_000] }),
+            "InvalidTierTable",
+        );
+    });
+
+    it('tiered scales the tiers that placed up to the whole pool', async () => {
+        // Three tiers but only two players: 5000 and 3000 parts of 8000.
+        const [contest, [alice, bob]] = await scoredContest(
+            mint,
+            { payoutScheme: { tiered: {} }, tierBps: [5_000, 3_000, 2_000] },
+            [
+                { bits: 0b0101, mask: 0b1111 }, // 4 correct
+                { bits: 0b0100, mask: 0b1111 }, // 3 correct
+            ],
+        );
+
+        assert.equal((await contest.fetch()).payoutTotal.toString(), PRIZE_POOL.toString());
+        assert.equal((await claimedAmount(contest, alice)).toString(), "1125000");
+        assert.equal((await claimedAmount(contest, bob)).toString(), "675000");
+
+        await settleContest(contest);
+        const state = await program.account.contest.fetch(contest.pda);
+        assert.ok(state.status.hasOwnProperty('settled'));
+        assert.equal(state.paidSoFar.toString(), PRIZE_POOL.toString());
+    });
+
+    it('proportional splits equally when nobody scored', async () => {
+        const [contest, [alice, bob]] = await scoredContest(mint, { payoutScheme: { proportional: {} } }, [
+            { bits: 0b1010, mask: 0b1111 },
+            { bits: 0b0010, mask: 0b0010 },
+        ]);
+
+        assert.equal((await contest.fetch()).totalScore.toString(), "0");
+        assert.equal((await claimedAmount(contest, alice)).toString(), "900000");
+        assert.equal((await claimedAmount(contest, bob)).toString(), "900000");
+    });
+});
@@ -0,0 +1,131 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    Contest,
+    Player,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    postAnswerKey,
+    program,
+    score,
+    setupConfig,
+    submitAnswers,
+} from "./utils";
+
+// Packs one option per question, `width` bits each, question 0 lowest.
+function pack(options: number[], width: number): number {
+    return options.reduce((packed, option, q) => packed | (option << (q * width)), 0);
+}
+
+async function scoreOf(contest: Contest, player: Player): Promise<number> {
+    return (await program.account.participant.fetch(contest.participant(player.kp.publicKey))).score;
+}
+
+describe('scoring', () => {
+    let mint: anchor.web3.PublicKey;
+    let alice: Player;
+    let bob: Player;
+
+    before(async () => {
+        mint = await newMint();
+        await setupConfig(mint);
+        [alice, bob] = await Promise.all([newPlayer(mint), newPlayer(mint)]);
+    });
+
+    describe('four options per question', () => {
+        let contest: Contest;
+
+        before(async () => {
+            contest = await createContest(mint, { questionCount: 3, maxAttempts: 3, optionCount: 4 });
+            await join(contest, alice);
+            await join(contest, bob);
+        });
+
+        it('rejects answer bits past the last question', async () => {
+            await expectError(submitAnswers(contest, alice, 1 << 6, 0b111), "InvalidAnswerBits");
+        });
+
+        it('scores two-bit answers against the key', async () => {
+            await submitAnswers(contest, alice, pack([3, 0, 2], 2), 0b111);
+            await submitAnswers(contest, bob, pack([3, 1, 2], 2), 0b111);
+            await lockContest(contest);
+            await postAnswerKey(contest, pack([3, 0, 2], 2));
+
+            await score(contest, alice);
+            await score(contest, bob);
+            assert.equal(await scoreOf(contest, alice), 3);
+            assert.equal(await scoreOf(contest, bob), 2);
+        });
+    });
+
+    describe('question weights', () => {
+        let contest: Contest;
+
+        it('rejects a weight table of the wrong length or with a zero weight', async () => {
+            await expectError(
+                createContest(mint, { questionCount: 3, maxAttempts: 3, questionWeights: [1, 3] }),
+                "InvalidQuestionWeights",
+            );
+            await expectError(
+                createContest(mint, { questionCount: 3, maxAttempts: 3, questionWeights: [1, 0, 5] }),
+                "InvalidQuestionWeights",
+            );
+        });
+
+        it('sums the weights of correctly answered questions', async () => {
+            contest = await createContest(mint, { questionCount: 3, maxAttempts: 3, questionWeights: [1, 3, 5] });
+            await join(contest, alice);
+            await join(contest, bob);
+            await submitAnswers(contest, alice, 0b101, 0b111);
+            // Misses only the 3-point question.
+            await submitAnswers(contest, bob, 0b111, 0b111);
+            await lockContest(contest);
+            await postAnswerKey(contest, 0b101);
+
+            await score(contest, alice);
+            await score(contest, bob);
+            assert.equal(await scoreOf(contest, alice), 9);
+            assert.equal(await scoreOf(contest, bob), 6);
+        });
+    });
+
+    describe('tiebreaker', () => {
+        let contest: Contest;
+
+        before(async () => {
+            contest = await createContest(mint, { questionCount: 2, maxAttempts: 2, hasTiebreaker: true });
+            await join(contest, alice);
+            await join(contest, bob);
+        });
+
+        it('requires a prediction exactly when the contest has a tiebreaker', async () => {
+            await expectError(submitAnswers(contest, alice, 0b01, 0b11), "InvalidTiebreaker");
+        });
+
+        it('ranks equal scores by distance to the tiebreaker result', async () => {
+            // Both answer everything correctly; bob's prediction is closer.
+            await submitAnswers(contest, alice, 0b01, 0b11, new BN(10));
+            await submitAnswers(contest, bob, 0b01, 0b11, new BN(4));
+            await lockContest(contest);
+            await postAnswerKey(contest, 0b01, 0, new BN(5));
+
+            // Scored in the order that would favour alice on insertion alone.
+            await score(contest, alice);
+            await score(contest, bob);
+
+            const { entries } = await program.account.leaderboard.fetch(contest.leaderboard);
+            assert.deepEqual(
+                entries.map((e) => [e.player.toBase58(), e.score, e.tiebreak.toNumber()]),
+                [
+                    [bob.kp.publicKey.toBase58(), 2, 1],
+                    [alice.kp.publicKey.toBase58(), 2, 5],
+                ],
+            );
+        });
+    });
+});
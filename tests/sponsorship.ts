@@ -0,0 +1,126 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+    Contest,
+    Player,
+    cancelContest,
+    createContest,
+    expectError,
+    join,
+    lockContest,
+    newMint,
+    newPlayer,
+    program,
+    setupConfig,
+    tokenBalance,
+} from "./utils";
+
+function sponsorshipPda(contest: Contest, sponsor: Player) {
+    return contest.derive("sponsorship", sponsor.kp.publicKey.toBuffer());
+}
+
+function fundPrizePool(contest: Contest, sponsor: Player, amount: number) {
+    return program.methods
+        .fundPrizePool(contest.id, new BN(amount))
+        .accountsStrict({
+            sponsor: sponsor.kp.publicKey,
+            contest: contest.pda,
+            sponsorship: sponsorshipPda(contest, sponsor),
+            vault: contest.vault,
+            sponsorToken: sponsor.ata,
+            poolMint: contest.mint,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sponsor.kp])
+        .rpc();
+}
+
+function refundSponsor(contest: Contest, sponsor: Player) {
+    // Permissionless: the provider wallet cranks the refund on the sponsor's behalf.
+    return program.methods
+        .refundSponsor(contest.id)
+        .accountsStrict({
+            sponsor: sponsor.kp.publicKey,
+            contest: contest.pda,
+            sponsorship: sponsorshipPda(contest, sponsor),
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            sponsorToken: sponsor.ata,
+            poolMint: contest.mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+}
+
+describe('sponsorship', () => {
+    let mint: anchor.web3.PublicKey;
+    let alice: Player;
+    let bob: Player;
+    let sponsor: Player;
+
+    before(async () => {
+        mint = await newMint();
+        await setupConfig(mint);
+        [alice, bob, sponsor] = await Promise.all([newPlayer(mint), newPlayer(mint), newPlayer(mint)]);
+    });
+
+    describe('funded contest', () => {
+        let contest: Contest;
+
+        before(async () => {
+            contest = await createContest(mint);
+            await join(contest, alice);
+            await join(contest, bob);
+        });
+
+        it('tracks sponsored amounts per contest and per sponsor', async () => {
+            await fundPrizePool(contest, sponsor, 300_000);
+            await fundPrizePool(contest, sponsor, 200_000);
+
+            assert.equal((await contest.fetch()).sponsoredAmount.toString(), "500000");
+            const sponsorship = await program.account.sponsorship.fetch(sponsorshipPda(contest, sponsor));
+            assert.equal(sponsorship.amount.toString(), "500000");
+            assert.isFalse(sponsorship.refunded);
+        });
+
+        it('adds sponsorship to the prize pool without taking rake from it', async () => {
+            await lockContest(contest);
+            // 2 entries * 1_000_000 less 10% rake, plus the full 500_000 sponsorship.
+            assert.equal((await contest.fetch()).prizePool.toString(), "2300000");
+        });
+
+        it('rejects funding once the contest is locked', async () => {
+            await expectError(fundPrizePool(contest, sponsor, 1), "ContestClosed");
+        });
+    });
+
+    describe('cancelled contest', () => {
+        let contest: Contest;
+
+        before(async () => {
+            contest = await createContest(mint);
+            await join(contest, alice);
+            await fundPrizePool(contest, sponsor, 400_000);
+        });
+
+        it('rejects sponsor refunds before the contest is cancelled', async () => {
+            await expectError(refundSponsor(contest, sponsor), "ContestNotCancelled");
+        });
+
+        it('refunds the sponsor exactly once after cancellation', async () => {
+            await cancelContest(contest);
+
+            const before = await tokenBalance(sponsor.ata);
+            await refundSponsor(contest, sponsor);
+            assert.equal((await tokenBalance(sponsor.ata)).sub(before).toString(), "400000");
+            assert.isTrue((await program.account.sponsorship.fetch(sponsorshipPda(contest, sponsor))).refunded);
+
+            await expectError(refundSponsor(contest, sponsor), "AlreadyRefunded");
+            // Only alice's entry fee is left for her own refund.
+            assert.equal((await tokenBalance(contest.vault)).toString(), "1000000");
+        });
+    });
+});
@@ -0,0 +1,46 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { expectError, payer, program, provider } from "./utils";
+
+const { PublicKey, SystemProgram } = anchor.web3;
+
+// Preloaded from tests/fixtures/legacy_stake.json (see Anchor.toml): a 57-byte
+// stake account from before the lock-mode fields, 250_000 staked at slot 1_000.
+const LEGACY_STAKER = new PublicKey("J7fPxPRkbVtkfhNiQ268ezw25FQkDMXwH2CUXgtLKYnF");
+const LEGACY_STAKE = new PublicKey("FA3sT71jjS9utnv8gmUELF7Sd4hMXj5oqRSVck3V6vem");
+const LEGACY_LEN = 57;
+const LEGACY_LOCK_PERIOD_SLOTS = 6_480_000;
+
+function migrateStake() {
+    return program.methods
+        .migrateStake()
+        .accountsStrict({
+            feePayer: payer.publicKey,
+            staker: LEGACY_STAKER,
+            stakeAcct: LEGACY_STAKE,
+            systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+}
+
+describe('stake migration', () => {
+    it('grows a legacy stake and keeps its slot-based unlock', async () => {
+        assert.equal((await provider.connection.getAccountInfo(LEGACY_STAKE)).data.length, LEGACY_LEN);
+
+        await migrateStake();
+
+        const stake = await program.account.stakeAccount.fetch(LEGACY_STAKE);
+        assert.ok(stake.owner.equals(LEGACY_STAKER));
+        assert.equal(stake.amount.toNumber(), 250_000);
+        assert.ok("slot" in stake.lockMode);
+        // Unlocks where the legacy program would have let it go: start slot + 6_480_000.
+        assert.equal(stake.startSlot.add(stake.lockPeriod).toNumber(), 1_000 + LEGACY_LOCK_PERIOD_SLOTS);
+
+        const info = await provider.connection.getAccountInfo(LEGACY_STAKE);
+        assert.isAtLeast(info.lamports, await provider.connection.getMinimumBalanceForRentExemption(info.data.length));
+    });
+
+    it('rejects migrating an account twice', async () => {
+        await expectError(migrateStake(), "StakeAlreadyMigrated");
+    });
+});
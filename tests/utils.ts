@@ -0,0 +1,532 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+    createMint,
+    getAccount,
+    getOrCreateAssociatedTokenAccount,
+    mintTo,
+    TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Spotwin } from "../target/types/spotwin";
+import { createHash } from "crypto";
+
+const { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, LAMPORTS_PER_SOL } = anchor.web3;
+
+anchor.setProvider(anchor.AnchorProvider.env());
+export const provider = anchor.getProvider() as anchor.AnchorProvider;
+export const program = anchor.workspace.Spotwin as Program<Spotwin>;
+export const payer = provider.wallet as anchor.Wallet;
+
+export const ZERO_32: number[] = Array(32).fill(0);
+
+export const [configPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId,
+);
+
+// sha256(0x00 || contest_id LE || player || amount LE), see programs/spotwin/src/merkle.rs
+export function payoutLeaf(contestId: BN, player: anchor.web3.PublicKey, amount: BN): Buffer {
+    return createHash("sha256")
+        .update(Buffer.concat([
+            Buffer.from([0]),
+            contestId.toArrayLike(Buffer, "le", 8),
+            player.toBuffer(),
+            amount.toArrayLike(Buffer, "le", 8),
+        ]))
+        .digest();
+}
+
+// sha256(0x01 || min || max)
+function hashPair(a: Buffer, b: Buffer): Buffer {
+    const [lo, hi] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+    return createHash("sha256").update(Buffer.concat([Buffer.from([1]), lo, hi])).digest();
+}
+
+// Builds a sorted-pair Merkle tree; an odd node at the end of a level is
+// carried up unchanged, so its proof has no entry for that level.
+export function payoutTree(leaves: Buffer[]): { root: Buffer; proofs: number[][][] } {
+    const proofs: Buffer[][] = leaves.map(() => []);
+    let level = leaves.map((leaf, i) => ({ hash: leaf, members: [i] }));
+    while (level.length > 1) {
+        const next: typeof level = [];
+        for (let i = 0; i < level.length; i += 2) {
+            const left = level[i];
+            const right = level[i + 1];
+            if (!right) {
+                next.push(left);
+                continue;
+            }
+            left.members.forEach(m => proofs[m].push(right.hash));
+            right.members.forEach(m => proofs[m].push(left.hash));
+            next.push({ hash: hashPair(left.hash, right.hash), members: [...left.members, ...right.members] });
+        }
+        level = next;
+    }
+    return { root: level[0].hash, proofs: proofs.map(p => p.map(h => Array.from(h))) };
+}
+
+// sha256(answer_key LE || tiebreaker_result LE || salt), see Contest::opens_commitment
+export function answerCommitment(answerKey: BN, salt: Buffer, tiebreakerResult: BN = new BN(0)): number[] {
+    return Array.from(
+        createHash("sha256")
+            .update(Buffer.concat([
+                answerKey.toArrayLike(Buffer, "le", 8),
+                tiebreakerResult.toTwos(64).toArrayLike(Buffer, "le", 8),
+                salt,
+            ]))
+            .digest()
+    );
+}
+
+// Fails unless `promise` rejects with the given Anchor error code name.
+export async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+        await promise;
+    } catch (err: any) {
+        const actual = err?.error?.errorCode?.code ?? err?.errorCode?.code;
+        if (actual !== undefined) {
+            assert.equal(actual, code);
+        } else {
+            assert.include(String(err?.logs ?? err), code);
+        }
+        return;
+    }
+    assert.fail(`expected ${code}`);
+}
+
+export async function tokenBalance(ata: anchor.web3.PublicKey): Promise<BN> {
+    const balance = await provider.connection.getTokenAccountBalance(ata);
+    return new BN(balance.value.amount);
+}
+
+export async function newMint(): Promise<anchor.web3.PublicKey> {
+    return createMint(provider.connection, payer.payer, payer.publicKey, null, 6);
+}
+
+// Creates the config singleton or points it at `mint`, with the payer as
+// admin and treasury.
+export async function setupConfig(mint: anchor.web3.PublicKey, overrides: Record<string, unknown> = {}) {
+    const params: any = {
+        treasury: payer.publicKey,
+        protocolFeeBps: 500,
+        maxRakeBps: 2_000,
+        allowedMints: [mint],
+        stakeMint: mint,
+        stakeLockMode: { slot: {} },
+        stakeLockPeriod: new BN(0),
+        disputeBondLamports: new BN(10_000_000),
+        feedProgram: PublicKey.default,
+        ...overrides,
+    };
+    if (await program.account.protocolConfig.fetchNullable(configPda)) {
+        await program.methods
+            .updateConfig(params, null)
+            .accountsStrict({ admin: payer.publicKey, config: configPda })
+            .rpc();
+    } else {
+        const [programData] = PublicKey.findProgramAddressSync(
+            [program.programId.toBuffer()],
+            new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+        );
+        await program.methods
+            .initializeConfig(params)
+            .accountsStrict({
+                admin: payer.publicKey,
+                config: configPda,
+                program: program.programId,
+                programData,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+    }
+}
+
+export interface Player {
+    kp: anchor.web3.Keypair;
+    ata: anchor.web3.PublicKey;
+}
+
+// A fresh funded wallet holding `amount` of `mint`.
+export async function newPlayer(mint: anchor.web3.PublicKey, amount = 100_000_000): Promise<Player> {
+    const kp = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(kp.publicKey, LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig, "confirmed");
+    const ata = await getOrCreateAssociatedTokenAccount(provider.connection, payer.payer, mint, kp.publicKey);
+    await mintTo(provider.connection, payer.payer, mint, ata.address, payer.payer, amount);
+    return { kp, ata: ata.address };
+}
+
+export function defaultContestParams(overrides: Record<string, unknown> = {}): any {
+    return {
+        entryFee: new BN(1_000_000),
+        lockSlot: new BN(0),
+        rakeBps: 1_000,
+        maxEntries: 0,
+        minEntries: 1,
+        payoutScheme: { merkleRoot: {} },
+        tierBps: [],
+        questionCount: 4,
+        minAttempts: 1,
+        maxAttempts: 4,
+        optionCount: 2,
+        questionWeights: [],
+        hasTiebreaker: false,
+        disputePeriodSlots: new BN(0),
+        resolutionMode: { manual: {} },
+        lockMode: { slot: {} },
+        lockUnixTimestamp: new BN(0),
+        metadata: { title: "", metadataUri: "", questionsHash: ZERO_32 },
+        answerCommitment: ZERO_32,
+        ...overrides,
+    };
+}
+
+// PDAs of one contest, created by the payer.
+export class Contest {
+    readonly idBuf: Buffer;
+    readonly pda: anchor.web3.PublicKey;
+    readonly vault: anchor.web3.PublicKey;
+    readonly vaultAuthority: anchor.web3.PublicKey;
+    readonly leaderboard: anchor.web3.PublicKey;
+
+    constructor(readonly id: BN, readonly mint: anchor.web3.PublicKey) {
+        this.idBuf = id.toArrayLike(Buffer, "le", 8);
+        this.pda = this.derive("contest");
+        this.vault = this.derive("vault", mint.toBuffer());
+        this.vaultAuthority = this.derive("vault_authority");
+        this.leaderboard = this.derive("leaderboard");
+    }
+
+    derive(seed: string, ...extra: Buffer[]): anchor.web3.PublicKey {
+        return PublicKey.findProgramAddressSync([Buffer.from(seed), this.idBuf, ...extra], program.programId)[0];
+    }
+
+    participant(player: anchor.web3.PublicKey) {
+        return this.derive("participant", player.toBuffer());
+    }
+
+    fetch() {
+        return program.account.contest.fetch(this.pda);
+    }
+}
+
+let nextContestId = Date.now() * 1_000;
+
+// Creates a contest that locks 1000 slots from now unless `lockSlot` is given.
+export async function createContest(
+    mint: anchor.web3.PublicKey,
+    overrides: Record<string, unknown> = {},
+): Promise<Contest> {
+    const contest = new Contest(new BN(nextContestId++), mint);
+    const params = defaultContestParams(overrides);
+    if (!("lockSlot" in overrides) && "slot" in params.lockMode) {
+        params.lockSlot = new BN((await provider.connection.getSlot()) + 1_000);
+    }
+    await program.methods
+        .createContest(contest.id, params)
+        .accountsStrict({
+            creator: payer.publicKey,
+            config: configPda,
+            contest: contest.pda,
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            poolMint: mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+    return contest;
+}
+
+export async function join(contest: Contest, player: Player) {
+    await program.methods
+        .joinContest(contest.id)
+        .accountsStrict({
+            player: player.kp.publicKey,
+            feePayer: payer.publicKey,
+            contest: contest.pda,
+            participant: contest.participant(player.kp.publicKey),
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            playerToken: player.ata,
+            poolMint: contest.mint,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([player.kp])
+        .rpc();
+}
+
+export async function submitAnswers(
+    contest: Contest,
+    player: Player,
+    answerBits: number,
+    attemptMask: number,
+    tiebreaker: BN | null = null,
+) {
+    await program.methods
+        .updateAnswers(contest.id, new BN(answerBits), attemptMask, tiebreaker)
+        .accountsStrict({
+            creator: payer.publicKey,
+            contest: contest.pda,
+            player: player.kp.publicKey,
+            participant: contest.participant(player.kp.publicKey),
+        })
+        .rpc();
+}
+
+export async function lockContest(contest: Contest) {
+    await program.methods
+        .lockContest(contest.id)
+        .accountsStrict({ creator: payer.publicKey, contest: contest.pda })
+        .rpc();
+}
+
+export function postAnswerKey(
+    contest: Contest,
+    answerKey: number | BN,
+    voidMask = 0,
+    tiebreaker: BN | null = null,
+    salt: number[] = ZERO_32,
+) {
+    return program.methods
+        .postAnswerKey(contest.id, new BN(answerKey), voidMask, tiebreaker, salt)
+        .accountsStrict({ creator: payer.publicKey, contest: contest.pda })
+        .rpc();
+}
+
+export async function score(contest: Contest, player: Player) {
+    await program.methods
+        .scoreParticipant(contest.id)
+        .accountsStrict({
+            cranker: payer.publicKey,
+            contest: contest.pda,
+            player: player.kp.publicKey,
+            participant: contest.participant(player.kp.publicKey),
+            leaderboard: contest.leaderboard,
+            systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+}
+
+export function claimPrize(contest: Contest, player: Player, amount: BN, proof: number[][]) {
+    return program.methods
+        .claimPrize(contest.id, amount, proof)
+        .accountsStrict({
+            player: player.kp.publicKey,
+            contest: contest.pda,
+            participant: contest.participant(player.kp.publicKey),
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            playerToken: player.ata,
+            poolMint: contest.mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([player.kp])
+        .rpc();
+}
+
+export function claimSchemePrize(contest: Contest, player: Player) {
+    return program.methods
+        .claimSchemePrize(contest.id)
+        .accountsStrict({
+            player: player.kp.publicKey,
+            contest: contest.pda,
+            participant: contest.participant(player.kp.publicKey),
+            leaderboard: contest.leaderboard,
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            playerToken: player.ata,
+            poolMint: contest.mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([player.kp])
+        .rpc();
+}
+
+export function refundEntry(contest: Contest, player: Player) {
+    // Permissionless: the provider wallet cranks the refund on the player's behalf.
+    return program.methods
+        .refundEntry(contest.id)
+        .accountsStrict({
+            player: player.kp.publicKey,
+            contest: contest.pda,
+            participant: contest.participant(player.kp.publicKey),
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            playerToken: player.ata,
+            poolMint: contest.mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+}
+
+export function cancelContest(contest: Contest, authority: anchor.web3.Keypair = payer.payer) {
+    return program.methods
+        .cancelContest(contest.id)
+        .accountsStrict({ authority: authority.publicKey, config: configPda, contest: contest.pda })
+        .signers([authority])
+        .rpc();
+}
+
+export async function postPayoutRoot(contest: Contest, root: Buffer, winnerCount: number, payoutTotal: BN) {
+    await program.methods
+        .postPayoutRoot(contest.id, Array.from(root), winnerCount, payoutTotal)
+        .accountsStrict({ creator: payer.publicKey, contest: contest.pda })
+        .rpc();
+}
+
+export async function settleContest(contest: Contest) {
+    await program.methods
+        .settleContest(contest.id)
+        .accountsStrict({ contest: contest.pda })
+        .rpc();
+}
+
+// Creator and treasury are both the payer here, so one token account takes both cuts.
+export function sweepFees(contest: Contest, payerAta: anchor.web3.PublicKey) {
+    return program.methods
+        .sweepFees(contest.id)
+        .accountsStrict({
+            creator: payer.publicKey,
+            contest: contest.pda,
+            vault: contest.vault,
+            vaultAuthority: contest.vaultAuthority,
+            creatorAta: payerAta,
+            config: configPda,
+            treasuryAta: payerAta,
+            poolMint: contest.mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+}
+
+export function disputePda(contest: Contest, player: Player, answerKeyRound: number) {
+    const round = Buffer.alloc(4);
+    round.writeUInt32LE(answerKeyRound);
+    return contest.derive("dispute", player.kp.publicKey.toBuffer(), round);
+}
+
+export async function openDispute(contest: Contest, player: Player, questions: number): Promise<anchor.web3.PublicKey> {
+    const dispute = disputePda(contest, player, (await contest.fetch()).answerKeyRound);
+    await program.methods
+        .openDispute(contest.id, questions)
+        .accountsStrict({
+            player: player.kp.publicKey,
+            contest: contest.pda,
+            participant: contest.participant(player.kp.publicKey),
+            config: configPda,
+            dispute,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([player.kp])
+        .rpc();
+    return dispute;
+}
+
+// Resolves `dispute` and returns how many lamports the disputer got back.
+export async function resolveDispute(contest: Contest, player: Player, dispute: anchor.web3.PublicKey, upheld: boolean) {
+    const before = await provider.connection.getBalance(player.kp.publicKey);
+    await program.methods
+        .resolveDispute(contest.id, upheld)
+        .accountsStrict({
+            admin: payer.publicKey,
+            config: configPda,
+            contest: contest.pda,
+            dispute,
+            disputer: player.kp.publicKey,
+            treasury: payer.publicKey,
+        })
+        .rpc();
+    return (await provider.connection.getBalance(player.kp.publicKey)) - before;
+}
+
+export async function waitForSlot(slot: number) {
+    while ((await provider.connection.getSlot()) < slot) {
+        await new Promise((resolve) => setTimeout(resolve, 400));
+    }
+}
+
+// Cluster time, the clock `LockMode::UnixTimestamp` deadlines are checked against.
+export async function chainTime(): Promise<number> {
+    return provider.connection.getBlockTime(await provider.connection.getSlot());
+}
+
+export async function waitForChainTime(unixTimestamp: number) {
+    while ((await chainTime()) < unixTimestamp) {
+        await new Promise((resolve) => setTimeout(resolve, 400));
+    }
+}
+
+export const [stakeVaultPda] = PublicKey.findProgramAddressSync([Buffer.from("stake_vault")], program.programId);
+export const [stakeAuthorityPda] = PublicKey.findProgramAddressSync([Buffer.from("stake_vault_auth")], program.programId);
+
+export function stakePda(staker: anchor.web3.PublicKey) {
+    return PublicKey.findProgramAddressSync([Buffer.from("stake"), staker.toBuffer()], program.programId)[0];
+}
+
+// The stake vault is a singleton: returns its mint, creating it for `mint`
+// (which must be the config's stake mint) on a fresh deployment.
+export async function ensureStakeVault(mint: anchor.web3.PublicKey): Promise<anchor.web3.PublicKey> {
+    if (await provider.connection.getAccountInfo(stakeVaultPda)) {
+        return (await getAccount(provider.connection, stakeVaultPda)).mint;
+    }
+    await program.methods
+        .initializeStake()
+        .accountsStrict({
+            payer: payer.publicKey,
+            config: configPda,
+            poolMint: mint,
+            stakeVault: stakeVaultPda,
+            stakeAuthority: stakeAuthorityPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+    return mint;
+}
+
+export function stakeTokens(staker: Player, amount: number) {
+    return program.methods
+        .stakeTokens(new BN(amount))
+        .accountsStrict({
+            staker: staker.kp.publicKey,
+            feePayer: payer.publicKey,
+            config: configPda,
+            stakeAcct: stakePda(staker.kp.publicKey),
+            stakeVault: stakeVaultPda,
+            stakeAuthority: stakeAuthorityPda,
+            stakerAta: staker.ata,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([staker.kp])
+        .rpc();
+}
+
+export function unstakeTokens(staker: Player, amount: number) {
+    return program.methods
+        .unstakeTokens(new BN(amount))
+        .accountsStrict({
+            staker: staker.kp.publicKey,
+            stakeAcct: stakePda(staker.kp.publicKey),
+            stakeVault: stakeVaultPda,
+            stakeAuthority: stakeAuthorityPda,
+            stakerAta: staker.ata,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([staker.kp])
+        .rpc();
+}
+
+export function crankLock(contest: Contest) {
+    return program.methods
+        .crankLock(contest.id)
+        .accountsStrict({ contest: contest.pda })
+        .rpc();
+}